@@ -1,8 +1,11 @@
 use crate::ffi;
 use std::ffi::{CStr, CString};
+use std::fmt;
+use std::marker::PhantomData;
 
+#[repr(transparent)]
 pub struct String {
-    ptr: *mut ffi::std_String_t,
+    pub(crate) ptr: *mut ffi::std_String_t,
 }
 
 impl String {
@@ -16,11 +19,28 @@ impl String {
     }
 }
 
-pub struct StringRef {
+impl fmt::Display for String {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+#[repr(transparent)]
+pub struct StringRef<'a> {
     pub(crate) ptr: *mut ffi::std_String_t,
+    _marker: PhantomData<&'a String>,
 }
 
-impl std::ops::Deref for StringRef {
+impl<'a> StringRef<'a> {
+    pub(crate) fn _from_ptr(ptr: *mut ffi::std_String_t) -> Self {
+        StringRef {
+            ptr,
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<'a> std::ops::Deref for StringRef<'a> {
     type Target = String;
 
     fn deref(&self) -> &Self::Target {
@@ -28,6 +48,22 @@ impl std::ops::Deref for StringRef {
     }
 }
 
+impl<'a> fmt::Display for StringRef<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl Default for String {
+    fn default() -> Self {
+        unsafe {
+            let mut ptr = std::ptr::null_mut();
+            ffi::std_String_default(&mut ptr);
+            String { ptr }
+        }
+    }
+}
+
 impl Drop for String {
     fn drop(&mut self) {
         unsafe {
@@ -36,4 +72,40 @@ impl Drop for String {
     }
 }
 
+pub(crate) struct DoubleVector {
+    pub(crate) ptr: *mut ffi::std_DoubleVector_t,
+}
 
+impl DoubleVector {
+    pub(crate) fn as_slice(&self) -> &[f64] {
+        unsafe {
+            let mut size = 0;
+            ffi::std_DoubleVector_size(self.ptr, &mut size);
+            if size == 0 {
+                return &[];
+            }
+
+            let mut data = std::ptr::null_mut();
+            ffi::std_DoubleVector_data(self.ptr, &mut data);
+            std::slice::from_raw_parts(data as *const f64, size)
+        }
+    }
+}
+
+impl Default for DoubleVector {
+    fn default() -> Self {
+        unsafe {
+            let mut ptr = std::ptr::null_mut();
+            ffi::std_DoubleVector_default(&mut ptr);
+            DoubleVector { ptr }
+        }
+    }
+}
+
+impl Drop for DoubleVector {
+    fn drop(&mut self) {
+        unsafe {
+            ffi::std_DoubleVector_dtor(self.ptr);
+        }
+    }
+}
@@ -0,0 +1,99 @@
+use std::fmt;
+
+pub use half::f16;
+
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Vec2h {
+    pub x: f16,
+    pub y: f16,
+}
+
+impl Vec2h {
+    pub fn new(x: f16, y: f16) -> Self {
+        Vec2h { x, y }
+    }
+}
+
+impl fmt::Display for Vec2h {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "[{}, {}]", self.x, self.y)
+    }
+}
+
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Vec3h {
+    pub x: f16,
+    pub y: f16,
+    pub z: f16,
+}
+
+impl Vec3h {
+    pub fn new(x: f16, y: f16, z: f16) -> Self {
+        Vec3h { x, y, z }
+    }
+}
+
+impl fmt::Display for Vec3h {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "[{}, {}, {}]", self.x, self.y, self.z)
+    }
+}
+
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Vec4h {
+    pub x: f16,
+    pub y: f16,
+    pub z: f16,
+    pub w: f16,
+}
+
+impl Vec4h {
+    pub fn new(x: f16, y: f16, z: f16, w: f16) -> Self {
+        Vec4h { x, y, z, w }
+    }
+}
+
+impl fmt::Display for Vec4h {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "[{}, {}, {}, {}]", self.x, self.y, self.z, self.w)
+    }
+}
+
+/// Half-precision quaternion with the same layout as GfQuath: the imaginary
+/// part followed by the real part.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Quath {
+    pub x: f16,
+    pub y: f16,
+    pub z: f16,
+    pub w: f16,
+}
+
+impl Quath {
+    pub const IDENTITY: Quath = Quath {
+        x: f16::ZERO,
+        y: f16::ZERO,
+        z: f16::ZERO,
+        w: f16::ONE,
+    };
+
+    pub fn from_xyzw(x: f16, y: f16, z: f16, w: f16) -> Self {
+        Quath { x, y, z, w }
+    }
+}
+
+impl Default for Quath {
+    fn default() -> Self {
+        Quath::IDENTITY
+    }
+}
+
+impl fmt::Display for Quath {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "[{}, {}, {}, {}]", self.x, self.y, self.z, self.w)
+    }
+}
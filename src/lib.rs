@@ -3,6 +3,7 @@ pub mod usd;
 pub mod tf;
 pub mod sdf;
 pub mod cpp;
+pub mod gf;
 pub mod vt;
 
 pub fn add(left: usize, right: usize) -> usize {
@@ -1,5 +1,6 @@
 use bbl_usd::usd::{self, Attribute, Object, PropertyKind};
-use glam::{Vec2, Vec3, Vec4};
+use bbl_usd::vt::ValueKind;
+use std::fmt::Display;
 
 pub fn main() {
     let stage = usd::Stage::open("bbl-usd/test01.usda").expect("failed to open stage");
@@ -32,180 +33,154 @@ pub fn main() {
 }
 
 fn print_attribute(attr: &Attribute) {
-    if let Some(value) = attr.get() {
-        if let Some(token) = value.as_token() {
-            println!(
-                "  {}: {} = {}",
-                attr.name().text(),
-                attr.type_name().as_token(),
-                token
-            );
-        } else if let Some(val) = value.as_asset_path() {
+    let name = attr.name();
+    let type_name = attr.type_name().as_token();
+
+    let value = match attr.get() {
+        Some(value) => value,
+        None => {
+            println!("  {}: {}", name, type_name);
+            return;
+        }
+    };
+
+    match value.kind() {
+        ValueKind::Token(token) => {
+            println!("  {}: {} = {}", name, type_name, token);
+        }
+        ValueKind::String(val) => {
+            println!("  {}: {} = \"{}\"", name, type_name, val.as_str());
+        }
+        ValueKind::AssetPath(val) => {
             let path = if val.resolved_path().is_empty() {
                 val.asset_path()
             } else {
                 val.resolved_path()
             };
 
-            println!(
-                "  {}: {} = @{}@",
-                attr.name().text(),
-                attr.type_name().as_token(),
-                path
-            );
-        } else if let Some(val) = value.get::<i32>() {
-            println!(
-                "  {}: {} = {}",
-                attr.name().text(),
-                attr.type_name().as_token(),
-                val
-            );
-        } else if let Some(val) = value.get::<f32>() {
-            println!(
-                "  {}: {} = {}",
-                attr.name().text(),
-                attr.type_name().as_token(),
-                val
-            );
-        } else if let Some(val) = value.get::<f64>() {
-            println!(
-                "  {}: {} = {}",
-                attr.name().text(),
-                attr.type_name().as_token(),
-                val
-            );
-        } else if let Some(val) = value.get::<bool>() {
-            println!(
-                "  {}: {} = {}",
-                attr.name().text(),
-                attr.type_name().as_token(),
-                val
-            );
-        } else if let Some(val) = value.get::<Vec2>() {
-            println!(
-                "  {}: {} = {}",
-                attr.name().text(),
-                attr.type_name().as_token(),
-                val
-            );
-        } else if let Some(val) = value.get::<Vec3>() {
-            println!(
-                "  {}: {} = {}",
-                attr.name().text(),
-                attr.type_name().as_token(),
-                val
-            );
-        } else if let Some(val) = value.get::<Vec4>() {
-            println!(
-                "  {}: {} = {}",
-                attr.name().text(),
-                attr.type_name().as_token(),
-                val
-            );
-        } else if let Some(val) = value.as_token_array() {
+            println!("  {}: {} = @{}@", name, type_name, path);
+        }
+        ValueKind::Bool(val) => println!("  {}: {} = {}", name, type_name, val),
+        ValueKind::Int(val) => println!("  {}: {} = {}", name, type_name, val),
+        ValueKind::Int64(val) => println!("  {}: {} = {}", name, type_name, val),
+        ValueKind::UInt(val) => println!("  {}: {} = {}", name, type_name, val),
+        ValueKind::UChar(val) => println!("  {}: {} = {}", name, type_name, val),
+        ValueKind::Half(val) => println!("  {}: {} = {}", name, type_name, val),
+        ValueKind::Float(val) => println!("  {}: {} = {}", name, type_name, val),
+        ValueKind::Double(val) => println!("  {}: {} = {}", name, type_name, val),
+        ValueKind::Vec2(val) => println!("  {}: {} = {}", name, type_name, val),
+        ValueKind::Vec3(val) => println!("  {}: {} = {}", name, type_name, val),
+        ValueKind::Vec4(val) => println!("  {}: {} = {}", name, type_name, val),
+        ValueKind::Vec2d(val) => println!("  {}: {} = {}", name, type_name, val),
+        ValueKind::Vec3d(val) => println!("  {}: {} = {}", name, type_name, val),
+        ValueKind::Vec4d(val) => println!("  {}: {} = {}", name, type_name, val),
+        ValueKind::Vec2h(val) => println!("  {}: {} = {}", name, type_name, val),
+        ValueKind::Vec3h(val) => println!("  {}: {} = {}", name, type_name, val),
+        ValueKind::Vec4h(val) => println!("  {}: {} = {}", name, type_name, val),
+        ValueKind::Vec2i(val) => println!("  {}: {} = {}", name, type_name, val),
+        ValueKind::Vec3i(val) => println!("  {}: {} = {}", name, type_name, val),
+        ValueKind::Vec4i(val) => println!("  {}: {} = {}", name, type_name, val),
+        ValueKind::Quatf(val) => println!("  {}: {} = {}", name, type_name, val),
+        ValueKind::Quatd(val) => println!("  {}: {} = {}", name, type_name, val),
+        ValueKind::Quath(val) => println!("  {}: {} = {}", name, type_name, val),
+        ValueKind::Matrix2d(val) => println!("  {}: {} = {}", name, type_name, val),
+        ValueKind::Matrix3d(val) => println!("  {}: {} = {}", name, type_name, val),
+        ValueKind::Matrix4d(val) => println!("  {}: {} = {}", name, type_name, val),
+        ValueKind::TimeCode(val) => println!("  {}: {} = {}", name, type_name, val),
+        ValueKind::TokenArray(val) => {
             println!(
                 "  {}: {} = [{}]",
-                attr.name().text(),
-                attr.type_name().as_token(),
+                name,
+                type_name,
                 val.iter()
                     .map(|x| x.text())
                     .collect::<Vec<&str>>()
                     .join(", ")
             );
-        } else if let Some(val) = value.as_int_array() {
-            let size = val.size();
-            let elide = if size < 8 { "" } else { "..." };
-            println!(
-                "  {}: {} = {}:[{}{}]",
-                attr.name().text(),
-                attr.type_name().as_token(),
-                size,
-                val.iter()
-                    .take(size.min(8))
-                    .map(|x| format!("{}", x))
-                    .collect::<Vec<String>>()
-                    .join(", "),
-                elide
-            );
-        } else if let Some(val) = value.as_float_array() {
-            let size = val.size();
-            let elide = if size < 8 { "" } else { "..." };
-            println!(
-                "  {}: {} = {}:[{}{}]",
-                attr.name().text(),
-                attr.type_name().as_token(),
-                size,
-                val.iter()
-                    .take(size.min(8))
-                    .map(|x| format!("{}", x))
-                    .collect::<Vec<String>>()
-                    .join(", "),
-                elide
-            );
-        } else if let Some(val) = value.as_double_array() {
-            let size = val.size();
-            let elide = if size < 8 { "" } else { "..." };
-            println!(
-                "  {}: {} = {}:[{}{}]",
-                attr.name().text(),
-                attr.type_name().as_token(),
-                size,
-                val.iter()
-                    .take(size.min(8))
-                    .map(|x| format!("{}", x))
-                    .collect::<Vec<String>>()
-                    .join(", "),
-                elide
-            );
-        } else if let Some(val) = value.as_vec2_array() {
-            let size = val.size();
-            let elide = if size < 5 { "" } else { "..." };
+        }
+        ValueKind::StringArray(val) => {
             println!(
-                "  {}: {} = {}:[{}{}]",
-                attr.name().text(),
-                attr.type_name().as_token(),
-                size,
+                "  {}: {} = [{}]",
+                name,
+                type_name,
                 val.iter()
-                    .take(size.min(5))
-                    .map(|x| format!("{}", x))
+                    .map(|x| format!("\"{}\"", x))
                     .collect::<Vec<String>>()
-                    .join(", "),
-                elide
+                    .join(", ")
             );
-        } else if let Some(val) = value.as_vec3_array() {
-            let size = val.size();
-            let elide = if size < 5 { "" } else { "..." };
+        }
+        ValueKind::AssetPathArray(val) => {
             println!(
-                "  {}: {} = {}:[{}{}]",
-                attr.name().text(),
-                attr.type_name().as_token(),
-                size,
+                "  {}: {} = [{}]",
+                name,
+                type_name,
                 val.iter()
-                    .take(size.min(5))
-                    .map(|x| format!("{}", x))
+                    .map(|x| format!("@{}@", x.asset_path()))
                     .collect::<Vec<String>>()
-                    .join(", "),
-                elide
+                    .join(", ")
             );
-        } else if let Some(val) = value.as_vec4_array() {
-            let size = val.size();
-            let elide = if size < 5 { "" } else { "..." };
-            println!(
-                "  {}: {} = {}:[{}{}]",
-                attr.name().text(),
-                attr.type_name().as_token(),
-                size,
-                val.iter()
-                    .take(size.min(5))
-                    .map(|x| format!("{}", x))
+        }
+        ValueKind::BoolArray(val) => print_array(&name, &type_name, val.size(), val.iter(), 8),
+        ValueKind::IntArray(val) => print_array(&name, &type_name, val.size(), val.iter(), 8),
+        ValueKind::Int64Array(val) => print_array(&name, &type_name, val.size(), val.iter(), 8),
+        ValueKind::UIntArray(val) => print_array(&name, &type_name, val.size(), val.iter(), 8),
+        ValueKind::UCharArray(val) => print_array(&name, &type_name, val.size(), val.iter(), 8),
+        ValueKind::HalfArray(val) => print_array(&name, &type_name, val.size(), val.iter(), 8),
+        ValueKind::FloatArray(val) => print_array(&name, &type_name, val.size(), val.iter(), 8),
+        ValueKind::DoubleArray(val) => print_array(&name, &type_name, val.size(), val.iter(), 8),
+        ValueKind::TimeCodeArray(val) => print_array(&name, &type_name, val.size(), val.iter(), 8),
+        ValueKind::Vec2Array(val) => print_array(&name, &type_name, val.size(), val.iter(), 5),
+        ValueKind::Vec3Array(val) => print_array(&name, &type_name, val.size(), val.iter(), 5),
+        ValueKind::Vec4Array(val) => print_array(&name, &type_name, val.size(), val.iter(), 5),
+        ValueKind::Vec2dArray(val) => print_array(&name, &type_name, val.size(), val.iter(), 5),
+        ValueKind::Vec3dArray(val) => print_array(&name, &type_name, val.size(), val.iter(), 5),
+        ValueKind::Vec4dArray(val) => print_array(&name, &type_name, val.size(), val.iter(), 5),
+        ValueKind::Vec2hArray(val) => print_array(&name, &type_name, val.size(), val.iter(), 5),
+        ValueKind::Vec3hArray(val) => print_array(&name, &type_name, val.size(), val.iter(), 5),
+        ValueKind::Vec4hArray(val) => print_array(&name, &type_name, val.size(), val.iter(), 5),
+        ValueKind::Vec2iArray(val) => print_array(&name, &type_name, val.size(), val.iter(), 5),
+        ValueKind::Vec3iArray(val) => print_array(&name, &type_name, val.size(), val.iter(), 5),
+        ValueKind::Vec4iArray(val) => print_array(&name, &type_name, val.size(), val.iter(), 5),
+        ValueKind::QuatfArray(val) => print_array(&name, &type_name, val.size(), val.iter(), 5),
+        ValueKind::QuatdArray(val) => print_array(&name, &type_name, val.size(), val.iter(), 5),
+        ValueKind::QuathArray(val) => print_array(&name, &type_name, val.size(), val.iter(), 5),
+        ValueKind::Matrix2dArray(val) => print_array(&name, &type_name, val.size(), val.iter(), 5),
+        ValueKind::Matrix3dArray(val) => print_array(&name, &type_name, val.size(), val.iter(), 5),
+        ValueKind::Matrix4dArray(val) => print_array(&name, &type_name, val.size(), val.iter(), 5),
+        ValueKind::Dictionary(dict) => {
+            println!(
+                "  {}: {} = {{{}}}",
+                name,
+                type_name,
+                dict.iter()
+                    .map(|(key, value)| format!("{}: {}", key, value))
                     .collect::<Vec<String>>()
-                    .join(", "),
-                elide
+                    .join(", ")
             );
-        } else {
-            println!("  {}: {}", attr.name().text(), attr.type_name().as_token());
         }
-    } else {
-        println!("  {}: {}", attr.name().text(), attr.type_name().as_token());
+        _ => {
+            println!("  {}: {}", name, type_name);
+        }
     }
 }
+
+fn print_array<T: Display>(
+    name: &dyn Display,
+    type_name: &dyn Display,
+    size: usize,
+    iter: impl Iterator<Item = T>,
+    max: usize,
+) {
+    let elide = if size < max { "" } else { "..." };
+    println!(
+        "  {}: {} = {}:[{}{}]",
+        name,
+        type_name,
+        size,
+        iter.take(size.min(max))
+            .map(|x| format!("{}", x))
+            .collect::<Vec<String>>()
+            .join(", "),
+        elide
+    );
+}
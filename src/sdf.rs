@@ -1,11 +1,45 @@
-use crate::{ffi, tf};
+use crate::{cpp, ffi, tf, vt};
+use std::cmp::Ordering;
 use std::ffi::{CStr, CString};
+use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::marker::PhantomData;
+use std::str::FromStr;
 
+#[derive(Debug)]
+pub enum Error {
+    InvalidPath { path: String, reason: String },
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::InvalidPath { path, reason } => {
+                write!(f, "invalid path \"{}\": {}", path, reason)
+            }
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+#[repr(transparent)]
 pub struct AssetPath {
     pub(crate) ptr: *mut ffi::sdf_AssetPath_t,
 }
 
 impl AssetPath {
+    /// Panics if `path` contains an interior nul byte.
+    pub fn new(path: &str) -> AssetPath {
+        let cs = CString::new(path).unwrap();
+
+        unsafe {
+            let mut ptr = std::ptr::null_mut();
+            ffi::sdf_AssetPath_from_string(cs.as_ptr() as *mut i8, &mut ptr);
+            AssetPath { ptr }
+        }
+    }
+
     pub fn asset_path(&self) -> &str {
         unsafe {
             let mut ptr = std::ptr::null_mut();
@@ -25,11 +59,22 @@ impl AssetPath {
     }
 }
 
-pub struct AssetPathRef {
+#[repr(transparent)]
+pub struct AssetPathRef<'a> {
     pub(crate) ptr: *mut ffi::sdf_AssetPath_t,
+    _marker: PhantomData<&'a AssetPath>,
 }
 
-impl std::ops::Deref for AssetPathRef {
+impl<'a> AssetPathRef<'a> {
+    pub(crate) fn _from_ptr(ptr: *mut ffi::sdf_AssetPath_t) -> Self {
+        AssetPathRef {
+            ptr,
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<'a> std::ops::Deref for AssetPathRef<'a> {
     type Target = AssetPath;
 
     fn deref(&self) -> &Self::Target {
@@ -45,11 +90,69 @@ impl Drop for AssetPath {
     }
 }
 
+#[repr(transparent)]
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Default)]
+pub struct TimeCode(pub f64);
+
+impl TimeCode {
+    pub fn new(time: f64) -> Self {
+        TimeCode(time)
+    }
+
+    pub fn value(&self) -> f64 {
+        self.0
+    }
+}
+
+impl From<f64> for TimeCode {
+    fn from(value: f64) -> Self {
+        TimeCode(value)
+    }
+}
+
+impl fmt::Display for TimeCode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
 pub struct Path {
     pub(crate) ptr: *mut ffi::sdf_Path_t,
 }
 
 impl Path {
+    pub fn absolute_root_path() -> Path {
+        unsafe {
+            let mut ptr = std::ptr::null_mut();
+            ffi::sdf_Path_AbsoluteRootPath(&mut ptr);
+            Path { ptr }
+        }
+    }
+
+    pub fn parse(text: &str) -> Result<Path, Error> {
+        let cs = CString::new(text).map_err(|_| Error::InvalidPath {
+            path: text.to_string(),
+            reason: "path contains an interior nul byte".to_string(),
+        })?;
+
+        unsafe {
+            let err_msg = cpp::String::default();
+            let mut valid = false;
+            ffi::sdf_Path_IsValidPathString(cs.as_ptr() as *mut i8, err_msg.ptr, &mut valid);
+
+            if !valid {
+                return Err(Error::InvalidPath {
+                    path: text.to_string(),
+                    reason: err_msg.as_str().to_string(),
+                });
+            }
+
+            let mut ptr = std::ptr::null_mut();
+            ffi::sdf_Path_from_string(cs.as_ptr() as *mut i8, &mut ptr);
+            Ok(Path { ptr })
+        }
+    }
+
     pub fn text(&self) -> &'static str {
         unsafe {
             let mut ptr = std::ptr::null_mut();
@@ -57,20 +160,258 @@ impl Path {
             CStr::from_ptr(ptr).to_str().unwrap()
         }
     }
+
+    pub fn name(&self) -> tf::TokenRef<'_> {
+        unsafe {
+            let mut ptr = std::ptr::null_mut();
+            ffi::sdf_Path_GetNameToken(self.ptr, &mut ptr);
+            tf::TokenRef::_from_ptr(ptr)
+        }
+    }
+
+    pub fn path_element_count(&self) -> usize {
+        unsafe {
+            let mut result = 0;
+            ffi::sdf_Path_GetPathElementCount(self.ptr, &mut result);
+            result
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        unsafe {
+            let mut result = false;
+            ffi::sdf_Path_IsEmpty(self.ptr, &mut result);
+            result
+        }
+    }
+
+    pub fn is_absolute_path(&self) -> bool {
+        unsafe {
+            let mut result = false;
+            ffi::sdf_Path_IsAbsolutePath(self.ptr, &mut result);
+            result
+        }
+    }
+
+    pub fn is_absolute_root_path(&self) -> bool {
+        unsafe {
+            let mut result = false;
+            ffi::sdf_Path_IsAbsoluteRootPath(self.ptr, &mut result);
+            result
+        }
+    }
+
+    pub fn is_prim_path(&self) -> bool {
+        unsafe {
+            let mut result = false;
+            ffi::sdf_Path_IsPrimPath(self.ptr, &mut result);
+            result
+        }
+    }
+
+    pub fn is_property_path(&self) -> bool {
+        unsafe {
+            let mut result = false;
+            ffi::sdf_Path_IsPropertyPath(self.ptr, &mut result);
+            result
+        }
+    }
+
+    pub fn parent_path(&self) -> Path {
+        unsafe {
+            let mut ptr = std::ptr::null_mut();
+            ffi::sdf_Path_GetParentPath(self.ptr, &mut ptr);
+            Path { ptr }
+        }
+    }
+
+    pub fn prim_path(&self) -> Path {
+        unsafe {
+            let mut ptr = std::ptr::null_mut();
+            ffi::sdf_Path_GetPrimPath(self.ptr, &mut ptr);
+            Path { ptr }
+        }
+    }
+
+    pub fn append_child<T: Into<tf::Token>>(&self, name: T) -> Path {
+        let name = name.into();
+        unsafe {
+            let mut ptr = std::ptr::null_mut();
+            ffi::sdf_Path_AppendChild(self.ptr, name.ptr, &mut ptr);
+            Path { ptr }
+        }
+    }
+
+    pub fn append_property<T: Into<tf::Token>>(&self, name: T) -> Path {
+        let name = name.into();
+        unsafe {
+            let mut ptr = std::ptr::null_mut();
+            ffi::sdf_Path_AppendProperty(self.ptr, name.ptr, &mut ptr);
+            Path { ptr }
+        }
+    }
+
+    pub fn has_prefix(&self, prefix: &Path) -> bool {
+        unsafe {
+            let mut result = false;
+            ffi::sdf_Path_HasPrefix(self.ptr, prefix.ptr, &mut result);
+            result
+        }
+    }
+
+    pub fn replace_prefix(&self, old_prefix: &Path, new_prefix: &Path) -> Path {
+        unsafe {
+            let mut ptr = std::ptr::null_mut();
+            ffi::sdf_Path_ReplacePrefix(self.ptr, old_prefix.ptr, new_prefix.ptr, &mut ptr);
+            Path { ptr }
+        }
+    }
+
+    pub fn common_prefix(&self, other: &Path) -> Path {
+        unsafe {
+            let mut ptr = std::ptr::null_mut();
+            ffi::sdf_Path_GetCommonPrefix(self.ptr, other.ptr, &mut ptr);
+            Path { ptr }
+        }
+    }
+
+    pub fn make_relative(&self, anchor: &Path) -> Path {
+        unsafe {
+            let mut ptr = std::ptr::null_mut();
+            ffi::sdf_Path_MakeRelativePath(self.ptr, anchor.ptr, &mut ptr);
+            Path { ptr }
+        }
+    }
+
+    pub fn make_absolute(&self, anchor: &Path) -> Path {
+        unsafe {
+            let mut ptr = std::ptr::null_mut();
+            ffi::sdf_Path_MakeAbsolutePath(self.ptr, anchor.ptr, &mut ptr);
+            Path { ptr }
+        }
+    }
+
+    /// Iterate over this path and each of its parents, stopping before the
+    /// absolute root or reflexive relative path.
+    pub fn ancestors(&self) -> Ancestors {
+        let path = self.clone();
+
+        Ancestors {
+            next: if path.path_element_count() == 0 {
+                None
+            } else {
+                Some(path)
+            },
+        }
+    }
+}
+
+pub struct Ancestors {
+    next: Option<Path>,
+}
+
+impl Iterator for Ancestors {
+    type Item = Path;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let current = self.next.take()?;
+
+        let count = current.path_element_count();
+        let parent = current.parent_path();
+        let parent_count = parent.path_element_count();
+
+        // relative paths such as "../a" have parents that grow rather than
+        // shrink, so only keep walking while we get closer to the root
+        if !parent.is_empty() && parent_count != 0 && parent_count < count {
+            self.next = Some(parent);
+        }
+
+        Some(current)
+    }
+}
+
+impl TryFrom<&str> for Path {
+    type Error = Error;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        Path::parse(value)
+    }
 }
 
-impl From<&str> for Path {
-    fn from(value: &str) -> Self {
-        let cs = std::ffi::CString::new(value).unwrap();
+impl FromStr for Path {
+    type Err = Error;
 
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Path::parse(s)
+    }
+}
+
+impl From<&Path> for Path {
+    fn from(value: &Path) -> Self {
+        value.clone()
+    }
+}
+
+impl Clone for Path {
+    fn clone(&self) -> Self {
         unsafe {
             let mut ptr = std::ptr::null_mut();
-            ffi::sdf_Path_from_string(cs.as_ptr() as *mut i8, &mut ptr);
+            ffi::sdf_Path_copy(self.ptr, &mut ptr);
             Path { ptr }
         }
     }
 }
 
+impl PartialEq for Path {
+    fn eq(&self, other: &Self) -> bool {
+        unsafe {
+            let mut result = false;
+            ffi::sdf_Path_op_eq(self.ptr, other.ptr, &mut result);
+            result
+        }
+    }
+}
+
+impl Eq for Path {}
+
+impl PartialOrd for Path {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Path {
+    fn cmp(&self, other: &Self) -> Ordering {
+        unsafe {
+            let mut less = false;
+            ffi::sdf_Path_op_lt(self.ptr, other.ptr, &mut less);
+            if less {
+                Ordering::Less
+            } else if self == other {
+                Ordering::Equal
+            } else {
+                Ordering::Greater
+            }
+        }
+    }
+}
+
+impl Hash for Path {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        unsafe {
+            let mut hash = 0;
+            ffi::sdf_Path_GetHash(self.ptr, &mut hash);
+            state.write_usize(hash);
+        }
+    }
+}
+
+impl fmt::Debug for Path {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("Path").field(&self.text()).finish()
+    }
+}
+
 pub struct PathRef {
     pub(crate) ptr: *mut ffi::sdf_Path_t,
 }
@@ -83,6 +424,12 @@ impl std::ops::Deref for PathRef {
     }
 }
 
+impl fmt::Debug for PathRef {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(&**self, f)
+    }
+}
+
 impl Drop for Path {
     fn drop(&mut self) {
         unsafe {
@@ -119,6 +466,31 @@ impl PathVector {
             end: self.size(),
         }
     }
+
+    pub fn push(&mut self, path: &Path) {
+        unsafe {
+            ffi::sdf_PathVector_push_back(self.ptr, path.ptr);
+        }
+    }
+}
+
+impl From<&[Path]> for PathVector {
+    fn from(value: &[Path]) -> Self {
+        let mut vec = PathVector::default();
+        for path in value {
+            vec.push(path);
+        }
+        vec
+    }
+}
+
+impl<'a> IntoIterator for &'a PathVector {
+    type Item = PathRef;
+    type IntoIter = PathVectorIterator<'a>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
 }
 
 impl Drop for PathVector {
@@ -164,6 +536,40 @@ pub struct ValueTypeName {
 }
 
 impl ValueTypeName {
+    pub fn find(name: &str) -> Option<ValueTypeName> {
+        let cs = CString::new(name).unwrap();
+
+        unsafe {
+            let mut ptr = std::ptr::null_mut();
+            ffi::sdf_ValueTypeName_find(cs.as_ptr() as *mut i8, &mut ptr);
+            let mut valid = false;
+            ffi::sdf_ValueTypeName_is_valid(ptr, &mut valid);
+
+            if valid {
+                Some(ValueTypeName { ptr })
+            } else {
+                ffi::sdf_ValueTypeName_dtor(ptr);
+                None
+            }
+        }
+    }
+
+    pub fn for_value(value: &vt::Value) -> Option<ValueTypeName> {
+        unsafe {
+            let mut ptr = std::ptr::null_mut();
+            ffi::sdf_ValueTypeName_find_for_value(value.ptr, &mut ptr);
+            let mut valid = false;
+            ffi::sdf_ValueTypeName_is_valid(ptr, &mut valid);
+
+            if valid {
+                Some(ValueTypeName { ptr })
+            } else {
+                ffi::sdf_ValueTypeName_dtor(ptr);
+                None
+            }
+        }
+    }
+
     pub fn as_token(&self) -> tf::Token {
         unsafe {
             let mut ptr = std::ptr::null_mut();
@@ -172,11 +578,11 @@ impl ValueTypeName {
         }
     }
 
-    pub fn role(&self) -> tf::TokenRef {
+    pub fn role(&self) -> tf::TokenRef<'_> {
         unsafe {
             let mut ptr = std::ptr::null_mut();
             ffi::sdf_ValueTypeName_GetRole(self.ptr, &mut ptr);
-            tf::TokenRef { ptr }
+            tf::TokenRef::_from_ptr(ptr)
         }
     }
 }
@@ -189,7 +595,83 @@ impl Drop for ValueTypeName {
     }
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum Variability {
     Varying,
     Uniform,
 }
+
+impl From<Variability> for ffi::sdf_Variability {
+    fn from(value: Variability) -> Self {
+        match value {
+            Variability::Varying => ffi::sdf_Variability::sdf_Variability_SdfVariabilityVarying,
+            Variability::Uniform => ffi::sdf_Variability::sdf_Variability_SdfVariabilityUniform,
+        }
+    }
+}
+
+impl From<ffi::sdf_Variability> for Variability {
+    fn from(value: ffi::sdf_Variability) -> Self {
+        match value {
+            ffi::sdf_Variability::sdf_Variability_SdfVariabilityUniform => Variability::Uniform,
+            _ => Variability::Varying,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Specifier {
+    Def,
+    Over,
+    Class,
+}
+
+impl From<Specifier> for ffi::sdf_Specifier {
+    fn from(value: Specifier) -> Self {
+        match value {
+            Specifier::Def => ffi::sdf_Specifier::sdf_Specifier_SdfSpecifierDef,
+            Specifier::Over => ffi::sdf_Specifier::sdf_Specifier_SdfSpecifierOver,
+            Specifier::Class => ffi::sdf_Specifier::sdf_Specifier_SdfSpecifierClass,
+        }
+    }
+}
+
+impl From<ffi::sdf_Specifier> for Specifier {
+    fn from(value: ffi::sdf_Specifier) -> Self {
+        match value {
+            ffi::sdf_Specifier::sdf_Specifier_SdfSpecifierOver => Specifier::Over,
+            ffi::sdf_Specifier::sdf_Specifier_SdfSpecifierClass => Specifier::Class,
+            _ => Specifier::Def,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ancestors(path: &str) -> Vec<&'static str> {
+        Path::parse(path)
+            .unwrap()
+            .ancestors()
+            .map(|p| p.text())
+            .collect()
+    }
+
+    #[test]
+    fn ancestors_stop_before_absolute_root() {
+        assert_eq!(ancestors("/a/b.c"), vec!["/a/b.c", "/a/b", "/a"]);
+        assert!(ancestors("/").is_empty());
+    }
+
+    #[test]
+    fn ancestors_stop_before_reflexive_relative_path() {
+        assert_eq!(ancestors("a/b"), vec!["a/b", "a"]);
+    }
+
+    #[test]
+    fn ancestors_stop_when_parent_grows() {
+        // the parent of ".." is "../..", which is further from the root
+        assert_eq!(ancestors("../a"), vec!["../a", ".."]);
+    }
+}
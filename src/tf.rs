@@ -1,12 +1,51 @@
 use crate::ffi;
+use std::cmp::Ordering;
 use std::ffi::{CStr, CString};
 use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::marker::PhantomData;
 
+#[derive(Debug)]
+pub enum Error {
+    InvalidToken { text: String },
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::InvalidToken { text } => {
+                write!(f, "invalid token {:?}: contains an interior nul byte", text)
+            }
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+#[repr(transparent)]
 pub struct Token {
     pub(crate) ptr: *mut ffi::tf_Token_t,
 }
 
 impl Token {
+    /// Panics if `text` contains an interior nul byte. Use `Token::try_new` to
+    /// handle that case.
+    pub fn new(text: &str) -> Token {
+        Token::try_new(text).expect("token text contains an interior nul byte")
+    }
+
+    pub fn try_new(text: &str) -> Result<Token, Error> {
+        let cs = CString::new(text).map_err(|_| Error::InvalidToken {
+            text: text.to_string(),
+        })?;
+
+        unsafe {
+            let mut ptr = std::ptr::null_mut();
+            ffi::tf_Token_from_string(cs.as_ptr() as *mut i8, &mut ptr);
+            Ok(Token { ptr })
+        }
+    }
+
     pub fn text(&self) -> &'static str {
         unsafe {
             let mut ptr = std::ptr::null_mut();
@@ -16,6 +55,83 @@ impl Token {
     }
 }
 
+impl Default for Token {
+    fn default() -> Self {
+        unsafe {
+            let mut ptr = std::ptr::null_mut();
+            ffi::tf_Token_default(&mut ptr);
+            Token { ptr }
+        }
+    }
+}
+
+/// Panics if `value` contains an interior nul byte, like `Token::new`.
+impl From<&str> for Token {
+    fn from(value: &str) -> Self {
+        Token::new(value)
+    }
+}
+
+impl Clone for Token {
+    fn clone(&self) -> Self {
+        unsafe {
+            let mut ptr = std::ptr::null_mut();
+            ffi::tf_Token_copy(self.ptr, &mut ptr);
+            Token { ptr }
+        }
+    }
+}
+
+impl PartialEq for Token {
+    fn eq(&self, other: &Self) -> bool {
+        unsafe {
+            let mut result = false;
+            ffi::tf_Token_op_eq(self.ptr, other.ptr, &mut result);
+            result
+        }
+    }
+}
+
+impl Eq for Token {}
+
+impl PartialOrd for Token {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Token {
+    fn cmp(&self, other: &Self) -> Ordering {
+        unsafe {
+            let mut less = false;
+            ffi::tf_Token_op_lt(self.ptr, other.ptr, &mut less);
+            if less {
+                Ordering::Less
+            } else if self == other {
+                Ordering::Equal
+            } else {
+                Ordering::Greater
+            }
+        }
+    }
+}
+
+impl Hash for Token {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        unsafe {
+            let mut hash = 0;
+            ffi::tf_Token_Hash(self.ptr, &mut hash);
+            state.write_usize(hash);
+        }
+    }
+}
+
+impl fmt::Debug for Token {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("Token").field(&self.text()).finish()
+    }
+}
+
 impl fmt::Display for Token {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(f, "{}", self.text())
@@ -30,11 +146,24 @@ impl Drop for Token {
     }
 }
 
-pub struct TokenRef {
+/// A token borrowed from the object that owns it, e.g. an element of a
+/// `vt::TokenArray` or the name of a prim.
+#[repr(transparent)]
+pub struct TokenRef<'a> {
     pub(crate) ptr: *mut ffi::tf_Token_t,
+    _marker: PhantomData<&'a Token>,
 }
 
-impl std::ops::Deref for TokenRef {
+impl<'a> TokenRef<'a> {
+    pub(crate) fn _from_ptr(ptr: *mut ffi::tf_Token_t) -> Self {
+        TokenRef {
+            ptr,
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<'a> std::ops::Deref for TokenRef<'a> {
     type Target = Token;
 
     fn deref(&self) -> &Self::Target {
@@ -42,7 +171,13 @@ impl std::ops::Deref for TokenRef {
     }
 }
 
-impl fmt::Display for TokenRef {
+impl<'a> fmt::Debug for TokenRef<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(&**self, f)
+    }
+}
+
+impl<'a> fmt::Display for TokenRef<'a> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(f, "{}", self.text())
     }
@@ -1,15 +1,45 @@
+use crate::cpp;
 use crate::ffi;
+use crate::gf;
 use crate::sdf;
 use crate::tf;
 use crate::vt;
 
+use std::cmp::Ordering;
+use std::convert::Infallible;
 use std::ffi::{CStr, CString};
+use std::fmt;
+use std::ops::{Add, AddAssign, Div, Mul, RangeInclusive, Sub, SubAssign};
 use std::path::Path;
 
 #[derive(Debug)]
 pub enum Error {
     StageOpen { filename: String },
+    StageCreate { filename: String },
+    StageCreateInMemory,
+    StageExport { filename: String },
     NoPrimAtPath { path: String },
+    DefinePrim { path: String },
+    OverridePrim { path: String },
+    RemovePrim { path: String },
+    CreateAttribute { path: String },
+    SetAttribute { path: String },
+    CreateRelationship { path: String },
+    EditRelationship { path: String },
+    SetMetadata { path: String, key: String },
+    InvalidPath(sdf::Error),
+}
+
+impl From<sdf::Error> for Error {
+    fn from(value: sdf::Error) -> Self {
+        Error::InvalidPath(value)
+    }
+}
+
+impl From<Infallible> for Error {
+    fn from(value: Infallible) -> Self {
+        match value {}
+    }
 }
 
 pub struct Stage {}
@@ -37,6 +67,51 @@ impl Stage {
             }
         }
     }
+
+    pub fn create_new<P: AsRef<Path>>(filename: P) -> Result<StageRefPtr, Error> {
+        unsafe {
+            let mut ptr = std::ptr::null_mut();
+            let initial_load_set = ffi::usd_StageInitialLoadSet::usd_StageInitialLoadSet_LoadAll;
+            let filename = filename.as_ref().to_string_lossy().to_string();
+            let c_filename = match CString::new(filename.clone()) {
+                Ok(c_filename) => c_filename,
+                Err(_) => return Err(Error::StageCreate { filename }),
+            };
+            ffi::usd_Stage_CreateNew(
+                c_filename.as_ptr() as *mut std::ffi::c_char,
+                initial_load_set,
+                &mut ptr,
+            );
+
+            let mut is_invalid = true;
+            ffi::usd_StageRefPtr_is_invalid(ptr, &mut is_invalid);
+
+            if is_invalid {
+                ffi::usd_StageRefPtr_dtor(ptr);
+                Err(Error::StageCreate { filename })
+            } else {
+                Ok(StageRefPtr { ptr })
+            }
+        }
+    }
+
+    pub fn create_in_memory() -> Result<StageRefPtr, Error> {
+        unsafe {
+            let mut ptr = std::ptr::null_mut();
+            let initial_load_set = ffi::usd_StageInitialLoadSet::usd_StageInitialLoadSet_LoadAll;
+            ffi::usd_Stage_CreateInMemory(initial_load_set, &mut ptr);
+
+            let mut is_invalid = true;
+            ffi::usd_StageRefPtr_is_invalid(ptr, &mut is_invalid);
+
+            if is_invalid {
+                ffi::usd_StageRefPtr_dtor(ptr);
+                Err(Error::StageCreateInMemory)
+            } else {
+                Ok(StageRefPtr { ptr })
+            }
+        }
+    }
 }
 
 pub struct StageRefPtr {
@@ -44,6 +119,14 @@ pub struct StageRefPtr {
 }
 
 impl StageRefPtr {
+    pub fn is_valid(&self) -> bool {
+        unsafe {
+            let mut is_invalid = true;
+            ffi::usd_StageRefPtr_is_invalid(self.ptr, &mut is_invalid);
+            !is_invalid
+        }
+    }
+
     pub fn pseudo_root(&self) -> Prim {
         unsafe {
             let mut ptr = std::ptr::null_mut();
@@ -52,8 +135,36 @@ impl StageRefPtr {
         }
     }
 
-    pub fn prim_at_path<P: Into<sdf::Path>>(&self, path: P) -> Result<Prim, Error> {
-        let path = path.into();
+    pub fn traverse(&self) -> PrimRange {
+        unsafe {
+            let mut ptr = std::ptr::null_mut();
+            ffi::usd_StageRefPtr_Traverse(self.ptr, &mut ptr);
+            PrimRange::_from_ptr(ptr)
+        }
+    }
+
+    pub fn traverse_with_predicate(&self, predicate: &PrimFlagsPredicate) -> PrimRange {
+        unsafe {
+            let mut ptr = std::ptr::null_mut();
+            ffi::usd_StageRefPtr_Traverse_with_predicate(self.ptr, predicate.ptr, &mut ptr);
+            PrimRange::_from_ptr(ptr)
+        }
+    }
+
+    pub fn traverse_all(&self) -> PrimRange {
+        unsafe {
+            let mut ptr = std::ptr::null_mut();
+            ffi::usd_StageRefPtr_TraverseAll(self.ptr, &mut ptr);
+            PrimRange::_from_ptr(ptr)
+        }
+    }
+
+    pub fn prim_at_path<P>(&self, path: P) -> Result<Prim, Error>
+    where
+        P: TryInto<sdf::Path>,
+        Error: From<P::Error>,
+    {
+        let path: sdf::Path = path.try_into()?;
         unsafe {
             let mut ptr = std::ptr::null_mut();
             ffi::usd_StageRefPtr_GetPrimAtPath(self.ptr, path.ptr, &mut ptr);
@@ -67,8 +178,243 @@ impl StageRefPtr {
             }
         }
     }
+
+    pub fn define_prim<P, T>(&self, path: P, type_name: T) -> Result<Prim, Error>
+    where
+        P: TryInto<sdf::Path>,
+        Error: From<P::Error>,
+        T: Into<tf::Token>,
+    {
+        let path: sdf::Path = path.try_into()?;
+        let type_name = type_name.into();
+        unsafe {
+            let mut ptr = std::ptr::null_mut();
+            ffi::usd_StageRefPtr_DefinePrim(self.ptr, path.ptr, type_name.ptr, &mut ptr);
+            let prim = Prim { ptr };
+
+            if prim.is_valid() {
+                Ok(prim)
+            } else {
+                Err(Error::DefinePrim { path: path.text().to_string() })
+            }
+        }
+    }
+
+    pub fn override_prim<P>(&self, path: P) -> Result<Prim, Error>
+    where
+        P: TryInto<sdf::Path>,
+        Error: From<P::Error>,
+    {
+        let path: sdf::Path = path.try_into()?;
+        unsafe {
+            let mut ptr = std::ptr::null_mut();
+            ffi::usd_StageRefPtr_OverridePrim(self.ptr, path.ptr, &mut ptr);
+            let prim = Prim { ptr };
+
+            if prim.is_valid() {
+                Ok(prim)
+            } else {
+                Err(Error::OverridePrim { path: path.text().to_string() })
+            }
+        }
+    }
+
+    pub fn remove_prim<P>(&self, path: P) -> Result<(), Error>
+    where
+        P: TryInto<sdf::Path>,
+        Error: From<P::Error>,
+    {
+        let path: sdf::Path = path.try_into()?;
+        unsafe {
+            let mut result = false;
+            ffi::usd_StageRefPtr_RemovePrim(self.ptr, path.ptr, &mut result);
+
+            if result {
+                Ok(())
+            } else {
+                Err(Error::RemovePrim { path: path.text().to_string() })
+            }
+        }
+    }
+
+    pub fn start_time_code(&self) -> f64 {
+        unsafe {
+            let mut result = 0.0;
+            ffi::usd_StageRefPtr_GetStartTimeCode(self.ptr, &mut result);
+            result
+        }
+    }
+
+    pub fn set_start_time_code(&self, time: f64) {
+        unsafe {
+            ffi::usd_StageRefPtr_SetStartTimeCode(self.ptr, time);
+        }
+    }
+
+    pub fn end_time_code(&self) -> f64 {
+        unsafe {
+            let mut result = 0.0;
+            ffi::usd_StageRefPtr_GetEndTimeCode(self.ptr, &mut result);
+            result
+        }
+    }
+
+    pub fn set_end_time_code(&self, time: f64) {
+        unsafe {
+            ffi::usd_StageRefPtr_SetEndTimeCode(self.ptr, time);
+        }
+    }
+
+    pub fn has_authored_time_code_range(&self) -> bool {
+        unsafe {
+            let mut result = false;
+            ffi::usd_StageRefPtr_HasAuthoredTimeCodeRange(self.ptr, &mut result);
+            result
+        }
+    }
+
+    pub fn time_codes_per_second(&self) -> f64 {
+        unsafe {
+            let mut result = 0.0;
+            ffi::usd_StageRefPtr_GetTimeCodesPerSecond(self.ptr, &mut result);
+            result
+        }
+    }
+
+    pub fn set_time_codes_per_second(&self, time_codes_per_second: f64) {
+        unsafe {
+            ffi::usd_StageRefPtr_SetTimeCodesPerSecond(self.ptr, time_codes_per_second);
+        }
+    }
+
+    pub fn frames_per_second(&self) -> f64 {
+        unsafe {
+            let mut result = 0.0;
+            ffi::usd_StageRefPtr_GetFramesPerSecond(self.ptr, &mut result);
+            result
+        }
+    }
+
+    pub fn set_frames_per_second(&self, frames_per_second: f64) {
+        unsafe {
+            ffi::usd_StageRefPtr_SetFramesPerSecond(self.ptr, frames_per_second);
+        }
+    }
+
+    /// Iterate over every frame between the stage's start and end time codes,
+    /// stepping by the number of time codes in one frame. Yields nothing if the
+    /// stage has no authored time code range.
+    pub fn frames(&self) -> FrameRange {
+        if !self.has_authored_time_code_range() {
+            return FrameRange::empty();
+        }
+
+        let fps = self.frames_per_second();
+        let step = if fps > 0.0 {
+            self.time_codes_per_second() / fps
+        } else {
+            1.0
+        };
+
+        FrameRange::new(self.start_time_code(), self.end_time_code(), step)
+    }
+
+    pub fn save(&self) {
+        unsafe {
+            ffi::usd_StageRefPtr_Save(self.ptr);
+        }
+    }
+
+    pub fn export<P: AsRef<Path>>(&self, filename: P) -> Result<(), Error> {
+        let filename = filename.as_ref().to_string_lossy().to_string();
+        let c_filename = match CString::new(filename.clone()) {
+            Ok(c_filename) => c_filename,
+            Err(_) => return Err(Error::StageExport { filename }),
+        };
+        unsafe {
+            let mut result = false;
+            ffi::usd_StageRefPtr_Export(
+                self.ptr,
+                c_filename.as_ptr() as *mut std::ffi::c_char,
+                true,
+                &mut result,
+            );
+
+            if result {
+                Ok(())
+            } else {
+                Err(Error::StageExport { filename })
+            }
+        }
+    }
+}
+
+pub struct FrameRange {
+    start: f64,
+    step: f64,
+    current: usize,
+    end: usize,
+}
+
+impl FrameRange {
+    pub fn new(start: f64, end: f64, step: f64) -> Self {
+        let finite = start.is_finite() && end.is_finite() && step.is_finite();
+        let end = if !finite || end < start || step <= 0.0 {
+            0
+        } else {
+            // allow for rounding error, relative to the number of steps, so
+            // that an end time code that lands on a step is included
+            let steps = (end - start) / step;
+            let nearest = steps.round();
+            if steps >= usize::MAX as f64 {
+                // more frames than can be counted
+                0
+            } else if (steps - nearest).abs() <= nearest.max(1.0) * 1e-9 {
+                nearest as usize + 1
+            } else {
+                steps.floor() as usize + 1
+            }
+        };
+
+        FrameRange {
+            start,
+            step,
+            current: 0,
+            end,
+        }
+    }
+
+    pub fn empty() -> Self {
+        FrameRange {
+            start: 0.0,
+            step: 0.0,
+            current: 0,
+            end: 0,
+        }
+    }
+}
+
+impl Iterator for FrameRange {
+    type Item = TimeCode;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.current == self.end {
+            None
+        } else {
+            let cur = self.current;
+            self.current += 1;
+            Some(TimeCode::new(self.start + cur as f64 * self.step))
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.end - self.current;
+        (remaining, Some(remaining))
+    }
 }
 
+impl ExactSizeIterator for FrameRange {}
+
 impl Drop for StageRefPtr {
     fn drop(&mut self) {
         unsafe {
@@ -88,122 +434,836 @@ pub trait Object {
         }
     }
 
-    fn name(&self) -> tf::TokenRef {
+    fn name(&self) -> tf::TokenRef<'_> {
         unsafe {
             let mut ptr = std::ptr::null_mut();
             ffi::usd_Object_GetName(self._object_ptr(), &mut ptr);
-            tf::TokenRef { ptr }
+            tf::TokenRef::_from_ptr(ptr)
         }
     }
-}
-
-pub struct Prim {
-    pub(crate) ptr: *mut ffi::usd_Prim_t,
-}
 
-impl Prim {
-    pub fn type_name(&self) -> tf::TokenRef {
+    fn custom_data(&self) -> vt::Dictionary {
         unsafe {
             let mut ptr = std::ptr::null_mut();
-            ffi::usd_Prim_GetTypeName(self.ptr, &mut ptr);
-            tf::TokenRef { ptr }
+            ffi::usd_Object_GetCustomData(self._object_ptr(), &mut ptr);
+            vt::Dictionary { ptr }
         }
     }
 
-    pub fn children(&self) -> PrimSiblingRange {
+    fn custom_data_by_key(&self, key_path: &str) -> Option<vt::Value> {
+        let key_path = tf::Token::try_new(key_path).ok()?;
         unsafe {
             let mut ptr = std::ptr::null_mut();
-            ffi::usd_Prim_GetChildren(self.ptr, &mut ptr);
-            PrimSiblingRange::_from_ptr(ptr)
+            ffi::usd_Object_GetCustomDataByKey(self._object_ptr(), key_path.ptr, &mut ptr);
+            let value = vt::Value { ptr };
+            if value.is_empty() {
+                None
+            } else {
+                Some(value)
+            }
         }
     }
 
-    pub fn properties(&self) -> PropertyVector {
+    fn set_custom_data_by_key(&self, key_path: &str, value: &vt::Value) -> Result<(), Error> {
+        let key_path = tf::Token::try_new(key_path).map_err(|_| Error::SetMetadata {
+            path: self.path().text().to_string(),
+            key: format!("customData:{}", key_path),
+        })?;
         unsafe {
-            let mut ptr = std::ptr::null_mut();
-            ffi::usd_Prim_GetProperties(self.ptr, &mut ptr);
-            PropertyVector { ptr }
+            ffi::usd_Object_SetCustomDataByKey(self._object_ptr(), key_path.ptr, value.ptr);
         }
+        Ok(())
     }
-}
 
-impl Object for Prim {
-    fn _object_ptr(&self) -> *mut ffi::usd_Object_t {
-        self.ptr as *mut ffi::usd_Object_t
+    fn asset_info(&self) -> vt::Dictionary {
+        unsafe {
+            let mut ptr = std::ptr::null_mut();
+            ffi::usd_Object_GetAssetInfo(self._object_ptr(), &mut ptr);
+            vt::Dictionary { ptr }
+        }
     }
-}
 
-impl Drop for Prim {
-    fn drop(&mut self) {
+    fn asset_info_by_key(&self, key_path: &str) -> Option<vt::Value> {
+        let key_path = tf::Token::try_new(key_path).ok()?;
         unsafe {
-            ffi::usd_Prim_dtor(self.ptr);
+            let mut ptr = std::ptr::null_mut();
+            ffi::usd_Object_GetAssetInfoByKey(self._object_ptr(), key_path.ptr, &mut ptr);
+            let value = vt::Value { ptr };
+            if value.is_empty() {
+                None
+            } else {
+                Some(value)
+            }
         }
     }
-}
-
-pub struct PrimRange {
-    ptr: *mut ffi::usd_PrimRange_t,
-    current: PrimRangeIterator,
-    end: PrimRangeIterator,
-}
 
-impl PrimRange {
-    pub(crate) fn _from_ptr(ptr: *mut ffi::usd_PrimRange_t) -> Self {
+    fn set_asset_info_by_key(&self, key_path: &str, value: &vt::Value) -> Result<(), Error> {
+        let key_path = tf::Token::try_new(key_path).map_err(|_| Error::SetMetadata {
+            path: self.path().text().to_string(),
+            key: format!("assetInfo:{}", key_path),
+        })?;
         unsafe {
-            let mut current = std::ptr::null_mut();
-            ffi::usd_PrimRange_begin(ptr, &mut current);
-
-            let mut end = std::ptr::null_mut();
-            ffi::usd_PrimRange_end(ptr, &mut end);
+            ffi::usd_Object_SetAssetInfoByKey(self._object_ptr(), key_path.ptr, value.ptr);
+        }
+        Ok(())
+    }
 
-            PrimRange {
-                ptr,
-                current: PrimRangeIterator { ptr: current },
-                end: PrimRangeIterator { ptr: end },
+    fn metadata(&self, key: &str) -> Option<vt::Value> {
+        let key = tf::Token::try_new(key).ok()?;
+        unsafe {
+            let mut ptr = std::ptr::null_mut();
+            ffi::vt_Value_new(&mut ptr);
+            let mut result = false;
+            ffi::usd_Object_GetMetadata(self._object_ptr(), key.ptr, ptr, &mut result);
+            let value = vt::Value { ptr };
+            if result && !value.is_empty() {
+                Some(value)
+            } else {
+                None
             }
         }
     }
 
-    pub fn from_prim(prim: &Prim) -> Self {
+    fn set_metadata(&self, key: &str, value: &vt::Value) -> Result<(), Error> {
+        let key = tf::Token::try_new(key).map_err(|_| Error::SetMetadata {
+            path: self.path().text().to_string(),
+            key: key.to_string(),
+        })?;
         unsafe {
-            let mut ptr = std::ptr::null_mut();
-            ffi::usd_PrimRange_from_prim(prim.ptr, &mut ptr);
-            PrimRange::_from_ptr(ptr)
+            let mut result = false;
+            ffi::usd_Object_SetMetadata(self._object_ptr(), key.ptr, value.ptr, &mut result);
+            if result {
+                Ok(())
+            } else {
+                Err(Error::SetMetadata {
+                    path: self.path().text().to_string(),
+                    key: key.text().to_string(),
+                })
+            }
         }
     }
 
-    pub fn begin(&self) -> PrimRangeIterator {
+    fn has_authored_metadata(&self, key: &str) -> bool {
+        let key = match tf::Token::try_new(key) {
+            Ok(key) => key,
+            Err(_) => return false,
+        };
         unsafe {
-            let mut ptr = std::ptr::null_mut();
-            ffi::usd_PrimRange_begin(self.ptr, &mut ptr);
-            PrimRangeIterator { ptr }
+            let mut result = false;
+            ffi::usd_Object_HasAuthoredMetadata(self._object_ptr(), key.ptr, &mut result);
+            result
         }
     }
 
-    pub fn end(&self) -> PrimRangeIterator {
+    fn hidden(&self) -> bool {
         unsafe {
-            let mut ptr = std::ptr::null_mut();
-            ffi::usd_PrimRange_end(self.ptr, &mut ptr);
+            let mut result = false;
+            ffi::usd_Object_IsHidden(self._object_ptr(), &mut result);
+            result
+        }
+    }
+
+    fn set_hidden(&self, hidden: bool) -> Result<(), Error> {
+        unsafe {
+            let mut result = false;
+            ffi::usd_Object_SetHidden(self._object_ptr(), hidden, &mut result);
+            if result {
+                Ok(())
+            } else {
+                Err(Error::SetMetadata {
+                    path: self.path().text().to_string(),
+                    key: "hidden".to_string(),
+                })
+            }
+        }
+    }
+
+    fn documentation(&self) -> String {
+        unsafe {
+            let mut ptr = std::ptr::null_mut();
+            ffi::usd_Object_GetDocumentation(self._object_ptr(), &mut ptr);
+            let documentation = cpp::String { ptr };
+            documentation.as_str().to_string()
+        }
+    }
+
+    fn set_documentation(&self, documentation: &str) -> Result<(), Error> {
+        let documentation = match CString::new(documentation) {
+            Ok(documentation) => documentation,
+            Err(_) => {
+                return Err(Error::SetMetadata {
+                    path: self.path().text().to_string(),
+                    key: "documentation".to_string(),
+                })
+            }
+        };
+        unsafe {
+            let mut result = false;
+            ffi::usd_Object_SetDocumentation(
+                self._object_ptr(),
+                documentation.as_ptr() as *mut i8,
+                &mut result,
+            );
+            if result {
+                Ok(())
+            } else {
+                Err(Error::SetMetadata {
+                    path: self.path().text().to_string(),
+                    key: "documentation".to_string(),
+                })
+            }
+        }
+    }
+}
+
+pub struct Prim {
+    pub(crate) ptr: *mut ffi::usd_Prim_t,
+}
+
+impl Prim {
+    pub fn type_name(&self) -> tf::TokenRef<'_> {
+        unsafe {
+            let mut ptr = std::ptr::null_mut();
+            ffi::usd_Prim_GetTypeName(self.ptr, &mut ptr);
+            tf::TokenRef::_from_ptr(ptr)
+        }
+    }
+
+    fn _from_ptr_if_valid(ptr: *mut ffi::usd_Prim_t) -> Option<Prim> {
+        let prim = Prim { ptr };
+        if prim.is_valid() {
+            Some(prim)
+        } else {
+            None
+        }
+    }
+
+    pub fn is_valid(&self) -> bool {
+        unsafe {
+            let mut result = false;
+            ffi::usd_Prim_IsValid(self.ptr, &mut result);
+            result
+        }
+    }
+
+    pub fn is_active(&self) -> bool {
+        unsafe {
+            let mut result = false;
+            ffi::usd_Prim_IsActive(self.ptr, &mut result);
+            result
+        }
+    }
+
+    pub fn set_active(&self, active: bool) -> Result<(), Error> {
+        unsafe {
+            let mut result = false;
+            ffi::usd_Prim_SetActive(self.ptr, active, &mut result);
+            if result {
+                Ok(())
+            } else {
+                Err(Error::SetMetadata {
+                    path: self.path().text().to_string(),
+                    key: "active".to_string(),
+                })
+            }
+        }
+    }
+
+    pub fn specifier(&self) -> sdf::Specifier {
+        unsafe {
+            let mut specifier = ffi::sdf_Specifier::sdf_Specifier_SdfSpecifierDef;
+            ffi::usd_Prim_GetSpecifier(self.ptr, &mut specifier);
+            specifier.into()
+        }
+    }
+
+    pub fn is_defined(&self) -> bool {
+        unsafe {
+            let mut result = false;
+            ffi::usd_Prim_IsDefined(self.ptr, &mut result);
+            result
+        }
+    }
+
+    pub fn is_abstract(&self) -> bool {
+        unsafe {
+            let mut result = false;
+            ffi::usd_Prim_IsAbstract(self.ptr, &mut result);
+            result
+        }
+    }
+
+    pub fn has_authored_type_name(&self) -> bool {
+        unsafe {
+            let mut result = false;
+            ffi::usd_Prim_HasAuthoredTypeName(self.ptr, &mut result);
+            result
+        }
+    }
+
+    pub fn is_loaded(&self) -> bool {
+        unsafe {
+            let mut result = false;
+            ffi::usd_Prim_IsLoaded(self.ptr, &mut result);
+            result
+        }
+    }
+
+    pub fn is_model(&self) -> bool {
+        unsafe {
+            let mut result = false;
+            ffi::usd_Prim_IsModel(self.ptr, &mut result);
+            result
+        }
+    }
+
+    pub fn is_group(&self) -> bool {
+        unsafe {
+            let mut result = false;
+            ffi::usd_Prim_IsGroup(self.ptr, &mut result);
+            result
+        }
+    }
+
+    pub fn is_component(&self) -> bool {
+        unsafe {
+            let mut result = false;
+            ffi::usd_Prim_IsComponent(self.ptr, &mut result);
+            result
+        }
+    }
+
+    pub fn kind(&self) -> Option<tf::Token> {
+        self.metadata("kind")
+            .and_then(|value| value.as_token().map(|kind| kind.clone()))
+    }
+
+    pub fn set_kind<T: Into<tf::Token>>(&self, kind: T) -> Result<(), Error> {
+        let kind: tf::Token = kind.into();
+        self.set_metadata("kind", &vt::ToValue::to_value(&kind))
+    }
+
+    pub fn is_pseudo_root(&self) -> bool {
+        unsafe {
+            let mut result = false;
+            ffi::usd_Prim_IsPseudoRoot(self.ptr, &mut result);
+            result
+        }
+    }
+
+    pub fn parent(&self) -> Option<Prim> {
+        unsafe {
+            let mut ptr = std::ptr::null_mut();
+            ffi::usd_Prim_GetParent(self.ptr, &mut ptr);
+            Prim::_from_ptr_if_valid(ptr)
+        }
+    }
+
+    pub fn child<T: Into<tf::Token>>(&self, name: T) -> Option<Prim> {
+        let name = name.into();
+        unsafe {
+            let mut ptr = std::ptr::null_mut();
+            ffi::usd_Prim_GetChild(self.ptr, name.ptr, &mut ptr);
+            Prim::_from_ptr_if_valid(ptr)
+        }
+    }
+
+    pub fn has_child<T: Into<tf::Token>>(&self, name: T) -> bool {
+        self.child(name).is_some()
+    }
+
+    pub fn next_sibling(&self) -> Option<Prim> {
+        unsafe {
+            let mut ptr = std::ptr::null_mut();
+            ffi::usd_Prim_GetNextSibling(self.ptr, &mut ptr);
+            Prim::_from_ptr_if_valid(ptr)
+        }
+    }
+
+    pub fn children(&self) -> PrimSiblingRange {
+        unsafe {
+            let mut ptr = std::ptr::null_mut();
+            ffi::usd_Prim_GetChildren(self.ptr, &mut ptr);
+            PrimSiblingRange::_from_ptr(ptr)
+        }
+    }
+
+    pub fn all_children(&self) -> PrimSiblingRange {
+        unsafe {
+            let mut ptr = std::ptr::null_mut();
+            ffi::usd_Prim_GetAllChildren(self.ptr, &mut ptr);
+            PrimSiblingRange::_from_ptr(ptr)
+        }
+    }
+
+    pub fn filtered_children(&self, predicate: &PrimFlagsPredicate) -> PrimSiblingRange {
+        unsafe {
+            let mut ptr = std::ptr::null_mut();
+            ffi::usd_Prim_GetFilteredChildren(self.ptr, predicate.ptr, &mut ptr);
+            PrimSiblingRange::_from_ptr(ptr)
+        }
+    }
+
+    pub fn properties(&self) -> PropertyVector {
+        unsafe {
+            let mut ptr = std::ptr::null_mut();
+            ffi::usd_Prim_GetProperties(self.ptr, &mut ptr);
+            PropertyVector { ptr }
+        }
+    }
+
+    pub fn authored_properties(&self) -> PropertyVector {
+        unsafe {
+            let mut ptr = std::ptr::null_mut();
+            ffi::usd_Prim_GetAuthoredProperties(self.ptr, &mut ptr);
+            PropertyVector { ptr }
+        }
+    }
+
+    pub fn properties_in_namespace(&self, namespaces: &str) -> PropertyVector {
+        // a namespace with an interior nul byte can't match any property
+        let namespaces = match CString::new(namespaces) {
+            Ok(namespaces) => namespaces,
+            Err(_) => return PropertyVector::default(),
+        };
+        unsafe {
+            let mut ptr = std::ptr::null_mut();
+            ffi::usd_Prim_GetPropertiesInNamespace(
+                self.ptr,
+                namespaces.as_ptr() as *mut i8,
+                &mut ptr,
+            );
+            PropertyVector { ptr }
+        }
+    }
+
+    pub fn attributes(&self) -> AttributeVector {
+        unsafe {
+            let mut ptr = std::ptr::null_mut();
+            ffi::usd_Prim_GetAttributes(self.ptr, &mut ptr);
+            AttributeVector { ptr }
+        }
+    }
+
+    pub fn attribute<T: Into<tf::Token>>(&self, name: T) -> Option<Attribute> {
+        let name = name.into();
+        unsafe {
+            let mut ptr = std::ptr::null_mut();
+            ffi::usd_Prim_GetAttribute(self.ptr, name.ptr, &mut ptr);
+            let mut valid = false;
+            ffi::usd_Attribute_IsValid(ptr, &mut valid);
+
+            if valid {
+                Some(Attribute { ptr })
+            } else {
+                ffi::usd_Attribute_dtor(ptr);
+                None
+            }
+        }
+    }
+
+    pub fn has_attribute<T: Into<tf::Token>>(&self, name: T) -> bool {
+        let name = name.into();
+        unsafe {
+            let mut result = false;
+            ffi::usd_Prim_HasAttribute(self.ptr, name.ptr, &mut result);
+            result
+        }
+    }
+
+    pub fn attribute_names(&self) -> Vec<tf::Token> {
+        self.attributes()
+            .iter()
+            .map(|attribute| attribute.name().clone())
+            .collect()
+    }
+
+    pub fn relationship<T: Into<tf::Token>>(&self, name: T) -> Option<Relationship> {
+        let name = name.into();
+        unsafe {
+            let mut ptr = std::ptr::null_mut();
+            ffi::usd_Prim_GetRelationship(self.ptr, name.ptr, &mut ptr);
+            let mut valid = false;
+            ffi::usd_Relationship_IsValid(ptr, &mut valid);
+
+            if valid {
+                Some(Relationship { ptr })
+            } else {
+                ffi::usd_Relationship_dtor(ptr);
+                None
+            }
+        }
+    }
+
+    pub fn has_relationship<T: Into<tf::Token>>(&self, name: T) -> bool {
+        let name = name.into();
+        unsafe {
+            let mut result = false;
+            ffi::usd_Prim_HasRelationship(self.ptr, name.ptr, &mut result);
+            result
+        }
+    }
+
+    pub fn create_attribute<T: Into<tf::Token>>(
+        &self,
+        name: T,
+        type_name: &sdf::ValueTypeName,
+        custom: bool,
+        variability: sdf::Variability,
+    ) -> Result<Attribute, Error> {
+        let name = name.into();
+        unsafe {
+            let mut ptr = std::ptr::null_mut();
+            ffi::usd_Prim_CreateAttribute(
+                self.ptr,
+                name.ptr,
+                type_name.ptr,
+                custom,
+                variability.into(),
+                &mut ptr,
+            );
+            let mut valid = false;
+            ffi::usd_Attribute_IsValid(ptr, &mut valid);
+
+            if valid {
+                Ok(Attribute { ptr })
+            } else {
+                ffi::usd_Attribute_dtor(ptr);
+                Err(Error::CreateAttribute {
+                    path: format!("{}.{}", self.path().text(), name.text()),
+                })
+            }
+        }
+    }
+
+    pub fn create_relationship<T: Into<tf::Token>>(
+        &self,
+        name: T,
+        custom: bool,
+    ) -> Result<Relationship, Error> {
+        let name = name.into();
+        unsafe {
+            let mut ptr = std::ptr::null_mut();
+            ffi::usd_Prim_CreateRelationship(self.ptr, name.ptr, custom, &mut ptr);
+            let mut valid = false;
+            ffi::usd_Relationship_IsValid(ptr, &mut valid);
+
+            if valid {
+                Ok(Relationship { ptr })
+            } else {
+                ffi::usd_Relationship_dtor(ptr);
+                Err(Error::CreateRelationship {
+                    path: format!("{}.{}", self.path().text(), name.text()),
+                })
+            }
+        }
+    }
+}
+
+impl Object for Prim {
+    fn _object_ptr(&self) -> *mut ffi::usd_Object_t {
+        self.ptr as *mut ffi::usd_Object_t
+    }
+}
+
+impl Drop for Prim {
+    fn drop(&mut self) {
+        unsafe {
+            ffi::usd_Prim_dtor(self.ptr);
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PrimFlag {
+    Active,
+    Loaded,
+    Model,
+    Group,
+    Abstract,
+    Defined,
+    HasDefiningSpecifier,
+    Instance,
+}
+
+impl From<PrimFlag> for ffi::usd_PrimFlags {
+    fn from(value: PrimFlag) -> Self {
+        match value {
+            PrimFlag::Active => ffi::usd_PrimFlags::usd_PrimFlags_Usd_PrimActiveFlag,
+            PrimFlag::Loaded => ffi::usd_PrimFlags::usd_PrimFlags_Usd_PrimLoadedFlag,
+            PrimFlag::Model => ffi::usd_PrimFlags::usd_PrimFlags_Usd_PrimModelFlag,
+            PrimFlag::Group => ffi::usd_PrimFlags::usd_PrimFlags_Usd_PrimGroupFlag,
+            PrimFlag::Abstract => ffi::usd_PrimFlags::usd_PrimFlags_Usd_PrimAbstractFlag,
+            PrimFlag::Defined => ffi::usd_PrimFlags::usd_PrimFlags_Usd_PrimDefinedFlag,
+            PrimFlag::HasDefiningSpecifier => {
+                ffi::usd_PrimFlags::usd_PrimFlags_Usd_PrimHasDefiningSpecifierFlag
+            }
+            PrimFlag::Instance => ffi::usd_PrimFlags::usd_PrimFlags_Usd_PrimInstanceFlag,
+        }
+    }
+}
+
+/// A conjunction of prim flags used to filter children and traversals, the
+/// equivalent of e.g. `UsdPrimIsActive && !UsdPrimIsAbstract`.
+pub struct PrimFlagsPredicate {
+    pub(crate) ptr: *mut ffi::usd_PrimFlagsPredicate_t,
+}
+
+impl PrimFlagsPredicate {
+    /// A predicate that accepts every prim.
+    pub fn tautology() -> Self {
+        unsafe {
+            let mut ptr = std::ptr::null_mut();
+            ffi::usd_PrimFlagsPredicate_Tautology(&mut ptr);
+            PrimFlagsPredicate { ptr }
+        }
+    }
+
+    /// `UsdPrimDefaultPredicate`: active, loaded, defined and non-abstract.
+    pub fn default_predicate() -> Self {
+        unsafe {
+            let mut ptr = std::ptr::null_mut();
+            ffi::usd_PrimFlagsPredicate_default(&mut ptr);
+            PrimFlagsPredicate { ptr }
+        }
+    }
+
+    pub fn require(self, flag: PrimFlag) -> Self {
+        unsafe {
+            ffi::usd_PrimFlagsPredicate_require(self.ptr, flag.into());
+        }
+        self
+    }
+
+    pub fn exclude(self, flag: PrimFlag) -> Self {
+        unsafe {
+            ffi::usd_PrimFlagsPredicate_exclude(self.ptr, flag.into());
+        }
+        self
+    }
+
+    pub fn traverse_instance_proxies(self, traverse: bool) -> Self {
+        unsafe {
+            ffi::usd_PrimFlagsPredicate_TraverseInstanceProxies(self.ptr, traverse);
+        }
+        self
+    }
+
+    pub fn includes_instance_proxies(&self) -> bool {
+        unsafe {
+            let mut result = false;
+            ffi::usd_PrimFlagsPredicate_IncludeInstanceProxiesInTraversal(self.ptr, &mut result);
+            result
+        }
+    }
+}
+
+impl Default for PrimFlagsPredicate {
+    fn default() -> Self {
+        PrimFlagsPredicate::default_predicate()
+    }
+}
+
+impl Drop for PrimFlagsPredicate {
+    fn drop(&mut self) {
+        unsafe {
+            ffi::usd_PrimFlagsPredicate_dtor(self.ptr);
+        }
+    }
+}
+
+pub struct PrimRange {
+    ptr: *mut ffi::usd_PrimRange_t,
+    current: PrimRangeIterator,
+    end: PrimRangeIterator,
+    // The iterator is advanced lazily so that prune_children() applies to the
+    // prim most recently returned by next().
+    advance: bool,
+    // Only needed for the depth reported by next_visit(), so it is looked up
+    // on first use.
+    root_element_count: Option<usize>,
+}
+
+impl PrimRange {
+    pub(crate) fn _from_ptr(ptr: *mut ffi::usd_PrimRange_t) -> Self {
+        unsafe {
+            let mut current = std::ptr::null_mut();
+            ffi::usd_PrimRange_begin(ptr, &mut current);
+
+            let mut end = std::ptr::null_mut();
+            ffi::usd_PrimRange_end(ptr, &mut end);
+
+            PrimRange {
+                ptr,
+                current: PrimRangeIterator { ptr: current },
+                end: PrimRangeIterator { ptr: end },
+                advance: false,
+                root_element_count: None,
+            }
+        }
+    }
+
+    pub fn from_prim(prim: &Prim) -> Self {
+        unsafe {
+            let mut ptr = std::ptr::null_mut();
+            ffi::usd_PrimRange_from_prim(prim.ptr, &mut ptr);
+            PrimRange::_from_ptr(ptr)
+        }
+    }
+
+    pub fn builder(prim: &Prim) -> PrimRangeBuilder {
+        PrimRangeBuilder {
+            prim,
+            predicate: None,
+            pre_and_post_visit: false,
+        }
+    }
+
+    pub fn begin(&self) -> PrimRangeIterator {
+        unsafe {
+            let mut ptr = std::ptr::null_mut();
+            ffi::usd_PrimRange_begin(self.ptr, &mut ptr);
             PrimRangeIterator { ptr }
         }
     }
+
+    pub fn end(&self) -> PrimRangeIterator {
+        unsafe {
+            let mut ptr = std::ptr::null_mut();
+            ffi::usd_PrimRange_end(self.ptr, &mut ptr);
+            PrimRangeIterator { ptr }
+        }
+    }
+
+    /// Skip the descendants of the prim last returned by `next()`. Has no
+    /// effect on a post-visit.
+    pub fn prune_children(&mut self) {
+        if self.advance && !self.current.is_post_visit() {
+            unsafe {
+                ffi::usd_PrimRangeIterator_PruneChildren(self.current.ptr);
+            }
+        }
+    }
+
+    /// Like `next()`, but also reports whether this is a post-visit and the
+    /// depth of the prim below the root of the range.
+    pub fn next_visit(&mut self) -> Option<PrimVisit> {
+        if self._advance() {
+            let root_element_count = match self.root_element_count {
+                Some(count) => count,
+                None => {
+                    let count = self.begin().deref().path().path_element_count();
+                    self.root_element_count = Some(count);
+                    count
+                }
+            };
+
+            let prim = self.current.deref();
+            let depth = prim.path().path_element_count() - root_element_count;
+            Some(PrimVisit {
+                prim,
+                post_visit: self.current.is_post_visit(),
+                depth,
+            })
+        } else {
+            None
+        }
+    }
+
+    pub fn visits(self) -> PrimVisits {
+        PrimVisits { range: self }
+    }
+
+    fn _advance(&mut self) -> bool {
+        if self.advance {
+            unsafe {
+                let mut dummy = std::ptr::null_mut();
+                ffi::usd_PrimRangeIterator_op_inc(self.current.ptr, &mut dummy);
+            }
+        }
+
+        self.advance = self.current != self.end;
+        self.advance
+    }
 }
 
 impl Iterator for PrimRange {
     type Item = Prim;
 
     fn next(&mut self) -> Option<Self::Item> {
-        if self.current == self.end {
-            None
+        if self._advance() {
+            Some(self.current.deref())
         } else {
-            unsafe {
-                let prim = self.current.deref();
+            None
+        }
+    }
+}
 
-                let mut dummy = std::ptr::null_mut();
-                ffi::usd_PrimRangeIterator_op_inc(self.current.ptr, &mut dummy);
+pub struct PrimVisit {
+    pub prim: Prim,
+    pub post_visit: bool,
+    pub depth: usize,
+}
 
-                Some(prim)
+pub struct PrimVisits {
+    range: PrimRange,
+}
+
+impl PrimVisits {
+    pub fn prune_children(&mut self) {
+        self.range.prune_children();
+    }
+}
+
+impl Iterator for PrimVisits {
+    type Item = PrimVisit;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.range.next_visit()
+    }
+}
+
+pub struct PrimRangeBuilder<'a> {
+    prim: &'a Prim,
+    predicate: Option<PrimFlagsPredicate>,
+    pre_and_post_visit: bool,
+}
+
+impl<'a> PrimRangeBuilder<'a> {
+    pub fn predicate(mut self, predicate: PrimFlagsPredicate) -> Self {
+        self.predicate = Some(predicate);
+        self
+    }
+
+    /// Visit each prim a second time once all of its descendants have been
+    /// visited. See `PrimRange::next_visit`.
+    pub fn pre_and_post_visit(mut self, pre_and_post_visit: bool) -> Self {
+        self.pre_and_post_visit = pre_and_post_visit;
+        self
+    }
+
+    pub fn build(self) -> PrimRange {
+        unsafe {
+            let mut ptr = std::ptr::null_mut();
+            match (&self.predicate, self.pre_and_post_visit) {
+                (Some(predicate), false) => {
+                    ffi::usd_PrimRange_from_prim_with_predicate(
+                        self.prim.ptr,
+                        predicate.ptr,
+                        &mut ptr,
+                    );
+                }
+                (None, false) => ffi::usd_PrimRange_from_prim(self.prim.ptr, &mut ptr),
+                (Some(predicate), true) => {
+                    ffi::usd_PrimRange_PreAndPostVisit_with_predicate(
+                        self.prim.ptr,
+                        predicate.ptr,
+                        &mut ptr,
+                    );
+                }
+                (None, true) => ffi::usd_PrimRange_PreAndPostVisit(self.prim.ptr, &mut ptr),
             }
+            PrimRange::_from_ptr(ptr)
         }
     }
 }
@@ -228,6 +1288,14 @@ impl PrimRangeIterator {
             Prim { ptr }
         }
     }
+
+    pub fn is_post_visit(&self) -> bool {
+        unsafe {
+            let mut result = false;
+            ffi::usd_PrimRangeIterator_IsPostVisit(self.ptr, &mut result);
+            result
+        }
+    }
 }
 
 impl Drop for PrimRangeIterator {
@@ -380,6 +1448,22 @@ pub struct Property {
 }
 
 impl Property {
+    pub fn is_attribute(&self) -> bool {
+        unsafe {
+            let mut result = false;
+            ffi::usd_Property_Is_Attribute(self.ptr, &mut result);
+            result
+        }
+    }
+
+    pub fn is_relationship(&self) -> bool {
+        unsafe {
+            let mut result = false;
+            ffi::usd_Property_Is_Relationship(self.ptr, &mut result);
+            result
+        }
+    }
+
     pub fn property_kind(&self) -> PropertyKind {
         unsafe {
             let mut is_attribute = false;
@@ -403,61 +1487,332 @@ impl Property {
     }
 }
 
-impl Object for Property {
+impl Object for Property {
+    fn _object_ptr(&self) -> *mut ffi::usd_Object_t {
+        self.ptr as *mut ffi::usd_Object_t
+    }
+}
+
+impl PropertyEx for Property {
+    fn _property_ptr(&self) -> *mut ffi::usd_Property_t {
+        self.ptr
+    }
+}
+
+impl Drop for Property {
+    fn drop(&mut self) {
+        unsafe {
+            ffi::usd_Property_dtor(self.ptr);
+        }
+    }
+}
+
+pub struct PropertyRef {
+    pub(crate) ptr: *mut ffi::usd_Property_t,
+}
+
+impl std::ops::Deref for PropertyRef {
+    type Target = Property;
+
+    fn deref(&self) -> &Self::Target {
+        unsafe { &*(self as *const PropertyRef as *const Property) }
+    }
+}
+
+pub struct PropertyVector {
+    pub(crate) ptr: *mut ffi::usd_PropertyVector_t,
+}
+
+impl PropertyVector {
+    pub fn size(&self) -> usize {
+        unsafe {
+            let mut size = 0;
+            ffi::usd_PropertyVector_size(self.ptr, &mut size);
+            size
+        }
+    }
+
+    pub fn at(&self, index: usize) -> PropertyRef {
+        unsafe {
+            let mut ptr = std::ptr::null_mut();
+            ffi::usd_PropertyVector_op_index(self.ptr, index, &mut ptr);
+            PropertyRef { ptr }
+        }
+    }
+
+    pub fn iter(&self) -> PropertyVectorIterator {
+        PropertyVectorIterator {
+            vec: self,
+            current: 0,
+            end: self.size(),
+        }
+    }
+}
+
+impl Default for PropertyVector {
+    fn default() -> Self {
+        unsafe {
+            let mut ptr = std::ptr::null_mut();
+            ffi::usd_PropertyVector_default(&mut ptr);
+            PropertyVector { ptr }
+        }
+    }
+}
+
+impl Drop for PropertyVector {
+    fn drop(&mut self) {
+        unsafe {
+            ffi::usd_PropertyVector_dtor(self.ptr);
+        }
+    }
+}
+
+pub struct PropertyVectorIterator<'a> {
+    vec: &'a PropertyVector,
+    current: usize,
+    end: usize,
+}
+
+impl<'a> Iterator for PropertyVectorIterator<'a> {
+    type Item = PropertyRef;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.current == self.end {
+            None
+        } else {
+            let cur = self.current;
+            self.current += 1;
+            Some(self.vec.at(cur))
+        }
+    }
+}
+
+impl<'a> IntoIterator for &'a PropertyVector {
+    type Item = PropertyRef;
+    type IntoIter = PropertyVectorIterator<'a>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+
+pub struct Attribute {
+    ptr: *mut ffi::usd_Attribute_t,
+}
+
+impl Attribute {
+    pub fn get(&self) -> Option<vt::Value> {
+        unsafe {
+            let mut ptr = std::ptr::null_mut();
+            ffi::vt_Value_new(&mut ptr);
+            let mut result = false;
+            ffi::usd_Attribute_Get(self.ptr, ptr, TimeCode::default().0, &mut result);
+            if result {
+                Some(vt::Value { ptr })
+            } else {
+                None
+            }
+        }
+    }
+
+    pub fn get_at(&self, time: TimeCode) -> Option<vt::Value> {
+        unsafe {
+            let mut ptr = std::ptr::null_mut();
+            ffi::vt_Value_new(&mut ptr);
+            let mut result = false;
+            ffi::usd_Attribute_Get(self.ptr, ptr, time.0, &mut result);
+            if result {
+                Some(vt::Value { ptr })
+            } else {
+                None
+            }
+        }
+    }
+
+    pub fn get_typed<T: AttributeValue + Default>(&self, time: TimeCode) -> Option<T> {
+        let mut value = T::default();
+        if self.get_into(&mut value, time) {
+            Some(value)
+        } else {
+            None
+        }
+    }
+
+    /// Read the value at `time` into `value`, reusing its storage. Returns
+    /// false if there is no value or it is not of type `T`.
+    pub fn get_into<T: AttributeValue>(&self, value: &mut T, time: TimeCode) -> bool {
+        unsafe { T::_get(self.ptr, value, time.0) }
+    }
+
+    pub fn time_samples(&self) -> Vec<f64> {
+        unsafe {
+            let times = cpp::DoubleVector::default();
+            let mut result = false;
+            ffi::usd_Attribute_GetTimeSamples(self.ptr, times.ptr, &mut result);
+            if result {
+                times.as_slice().to_vec()
+            } else {
+                Vec::new()
+            }
+        }
+    }
+
+    pub fn time_samples_in_interval(&self, interval: RangeInclusive<f64>) -> Vec<f64> {
+        unsafe {
+            let times = cpp::DoubleVector::default();
+            let mut result = false;
+            ffi::usd_Attribute_GetTimeSamplesInInterval(
+                self.ptr,
+                *interval.start(),
+                *interval.end(),
+                times.ptr,
+                &mut result,
+            );
+            if result {
+                times.as_slice().to_vec()
+            } else {
+                Vec::new()
+            }
+        }
+    }
+
+    pub fn num_time_samples(&self) -> usize {
+        unsafe {
+            let mut result = 0;
+            ffi::usd_Attribute_GetNumTimeSamples(self.ptr, &mut result);
+            result
+        }
+    }
+
+    pub fn bracketing_time_samples(&self, desired_time: f64) -> Option<(f64, f64)> {
+        unsafe {
+            let mut lower = 0.0;
+            let mut upper = 0.0;
+            let mut has_time_samples = false;
+            let mut result = false;
+            ffi::usd_Attribute_GetBracketingTimeSamples(
+                self.ptr,
+                desired_time,
+                &mut lower,
+                &mut upper,
+                &mut has_time_samples,
+                &mut result,
+            );
+            if result && has_time_samples {
+                Some((lower, upper))
+            } else {
+                None
+            }
+        }
+    }
+
+    pub fn value_might_be_time_varying(&self) -> bool {
+        unsafe {
+            let mut result = false;
+            ffi::usd_Attribute_ValueMightBeTimeVarying(self.ptr, &mut result);
+            result
+        }
+    }
+
+    pub fn set<T: vt::ToValue + ?Sized>(&self, value: &T) -> Result<(), Error> {
+        self.set_at(value, TimeCode::default())
+    }
+
+    pub fn set_at<T: vt::ToValue + ?Sized>(&self, value: &T, time: TimeCode) -> Result<(), Error> {
+        let value = value.to_value();
+        unsafe {
+            let mut result = false;
+            ffi::usd_Attribute_Set(self.ptr, value.ptr, time.0, &mut result);
+
+            if result {
+                Ok(())
+            } else {
+                Err(Error::SetAttribute { path: self.path().text().to_string() })
+            }
+        }
+    }
+
+    pub fn type_name(&self) -> sdf::ValueTypeName {
+        unsafe {
+            let mut ptr = std::ptr::null_mut();
+            ffi::usd_Attribute_GetTypeName(self.ptr, &mut ptr);
+            sdf::ValueTypeName { ptr }
+        }
+    }
+
+    pub fn variability(&self) -> sdf::Variability {
+        unsafe {
+            let mut variability = ffi::sdf_Variability::sdf_Variability_SdfVariabilityVarying;
+            ffi::usd_Attribute_GetVariability(self.ptr, &mut variability);
+            variability.into()
+        }
+    }
+}
+
+impl Object for Attribute {
     fn _object_ptr(&self) -> *mut ffi::usd_Object_t {
         self.ptr as *mut ffi::usd_Object_t
     }
 }
 
-impl PropertyEx for Property {
+impl PropertyEx for Attribute {
     fn _property_ptr(&self) -> *mut ffi::usd_Property_t {
-        self.ptr
+        self.ptr as *mut ffi::usd_Property_t
     }
 }
 
-impl Drop for Property {
+impl std::ops::Deref for Attribute {
+    type Target = Property;
+
+    fn deref(&self) -> &Self::Target {
+        unsafe { &*(self as *const Attribute as *const Property) }
+    }
+}
+
+impl Drop for Attribute {
     fn drop(&mut self) {
         unsafe {
-            ffi::usd_Property_dtor(self.ptr);
+            ffi::usd_Attribute_dtor(self.ptr);
         }
     }
 }
 
-pub struct PropertyRef {
-    pub(crate) ptr: *mut ffi::usd_Property_t,
+pub struct AttributeRef {
+    pub(crate) ptr: *mut ffi::usd_Attribute_t,
 }
 
-impl std::ops::Deref for PropertyRef {
-    type Target = Property;
+impl std::ops::Deref for AttributeRef {
+    type Target = Attribute;
 
     fn deref(&self) -> &Self::Target {
-        unsafe { &*(self as *const PropertyRef as *const Property) }
+        unsafe { &*(self as *const AttributeRef as *const Attribute) }
     }
 }
 
-pub struct PropertyVector {
-    pub(crate) ptr: *mut ffi::usd_PropertyVector_t,
+pub struct AttributeVector {
+    ptr: *mut ffi::usd_AttributeVector_t,
 }
 
-impl PropertyVector {
+impl AttributeVector {
     pub fn size(&self) -> usize {
         unsafe {
             let mut size = 0;
-            ffi::usd_PropertyVector_size(self.ptr, &mut size);
+            ffi::usd_AttributeVector_size(self.ptr, &mut size);
             size
         }
     }
 
-    pub fn at(&self, index: usize) -> PropertyRef {
+    pub fn at(&self, index: usize) -> AttributeRef {
         unsafe {
             let mut ptr = std::ptr::null_mut();
-            ffi::usd_PropertyVector_op_index(self.ptr, index, &mut ptr);
-            PropertyRef { ptr }
+            ffi::usd_AttributeVector_op_index(self.ptr, index, &mut ptr);
+            AttributeRef { ptr }
         }
     }
 
-    pub fn iter(&self) -> PropertyVectorIterator {
-        PropertyVectorIterator {
+    pub fn iter(&self) -> AttributeVectorIterator {
+        AttributeVectorIterator {
             vec: self,
             current: 0,
             end: self.size(),
@@ -465,22 +1820,22 @@ impl PropertyVector {
     }
 }
 
-impl Drop for PropertyVector {
+impl Drop for AttributeVector {
     fn drop(&mut self) {
         unsafe {
-            ffi::usd_PropertyVector_dtor(self.ptr);
+            ffi::usd_AttributeVector_dtor(self.ptr);
         }
     }
 }
 
-pub struct PropertyVectorIterator<'a> {
-    vec: &'a PropertyVector,
+pub struct AttributeVectorIterator<'a> {
+    vec: &'a AttributeVector,
     current: usize,
     end: usize,
 }
 
-impl<'a> Iterator for PropertyVectorIterator<'a> {
-    type Item = PropertyRef;
+impl<'a> Iterator for AttributeVectorIterator<'a> {
+    type Item = AttributeRef;
 
     fn next(&mut self) -> Option<Self::Item> {
         if self.current == self.end {
@@ -493,98 +1848,107 @@ impl<'a> Iterator for PropertyVectorIterator<'a> {
     }
 }
 
-impl<'a> IntoIterator for &'a PropertyVector {
-    type Item = PropertyRef;
-    type IntoIter = PropertyVectorIterator<'a>;
+impl<'a> IntoIterator for &'a AttributeVector {
+    type Item = AttributeRef;
+    type IntoIter = AttributeVectorIterator<'a>;
 
     fn into_iter(self) -> Self::IntoIter {
         self.iter()
     }
 }
 
-
-pub struct Attribute {
-    ptr: *mut ffi::usd_Attribute_t,
+pub struct AttributeQuery {
+    ptr: *mut ffi::usd_AttributeQuery_t,
 }
 
-impl Attribute {
-    pub fn get(&self) -> Option<vt::Value> {
+impl AttributeQuery {
+    pub fn new(attribute: &Attribute) -> Self {
         unsafe {
             let mut ptr = std::ptr::null_mut();
-            ffi::vt_Value_new(&mut ptr);
+            ffi::usd_AttributeQuery_from_attribute(attribute.ptr, &mut ptr);
+            AttributeQuery { ptr }
+        }
+    }
+
+    pub fn attribute(&self) -> AttributeRef {
+        unsafe {
+            let mut ptr = std::ptr::null_mut();
+            ffi::usd_AttributeQuery_GetAttribute(self.ptr, &mut ptr);
+            AttributeRef { ptr }
+        }
+    }
+
+    pub fn is_valid(&self) -> bool {
+        unsafe {
             let mut result = false;
-            ffi::usd_Attribute_Get(self.ptr, ptr, TimeCode::default().0, &mut result);
-            if result {
-                Some(vt::Value { ptr })
-            } else {
-                None
-            }
+            ffi::usd_AttributeQuery_IsValid(self.ptr, &mut result);
+            result
         }
     }
 
+    pub fn get(&self) -> Option<vt::Value> {
+        self.get_at(TimeCode::default())
+    }
+
     pub fn get_at(&self, time: TimeCode) -> Option<vt::Value> {
         unsafe {
             let mut ptr = std::ptr::null_mut();
             ffi::vt_Value_new(&mut ptr);
             let mut result = false;
-            ffi::usd_Attribute_Get(self.ptr, ptr, time.0, &mut result);
+            let value = vt::Value { ptr };
+            ffi::usd_AttributeQuery_Get(self.ptr, value.ptr, time.0, &mut result);
             if result {
-                Some(vt::Value { ptr })
+                Some(value)
             } else {
                 None
             }
         }
     }
 
-    pub fn type_name(&self) -> sdf::ValueTypeName {
+    pub fn time_samples(&self) -> Vec<f64> {
         unsafe {
-            let mut ptr = std::ptr::null_mut();
-            ffi::usd_Attribute_GetTypeName(self.ptr, &mut ptr);
-            sdf::ValueTypeName { ptr }
+            let times = cpp::DoubleVector::default();
+            let mut result = false;
+            ffi::usd_AttributeQuery_GetTimeSamples(self.ptr, times.ptr, &mut result);
+            if result {
+                times.as_slice().to_vec()
+            } else {
+                Vec::new()
+            }
         }
     }
-}
 
-impl Object for Attribute {
-    fn _object_ptr(&self) -> *mut ffi::usd_Object_t {
-        self.ptr as *mut ffi::usd_Object_t
+    pub fn num_time_samples(&self) -> usize {
+        unsafe {
+            let mut result = 0;
+            ffi::usd_AttributeQuery_GetNumTimeSamples(self.ptr, &mut result);
+            result
+        }
     }
-}
 
-impl PropertyEx for Attribute {
-    fn _property_ptr(&self) -> *mut ffi::usd_Property_t {
-        self.ptr as *mut ffi::usd_Property_t
+    pub fn value_might_be_time_varying(&self) -> bool {
+        unsafe {
+            let mut result = false;
+            ffi::usd_AttributeQuery_ValueMightBeTimeVarying(self.ptr, &mut result);
+            result
+        }
     }
 }
 
-impl std::ops::Deref for Attribute {
-    type Target = Property;
-
-    fn deref(&self) -> &Self::Target {
-        unsafe { &*(self as *const Attribute as *const Property) }
+impl From<&Attribute> for AttributeQuery {
+    fn from(attribute: &Attribute) -> Self {
+        AttributeQuery::new(attribute)
     }
 }
 
-impl Drop for Attribute {
+impl Drop for AttributeQuery {
     fn drop(&mut self) {
         unsafe {
-            ffi::usd_Attribute_dtor(self.ptr);
+            ffi::usd_AttributeQuery_dtor(self.ptr);
         }
     }
 }
 
-pub struct AttributeRef {
-    pub(crate) ptr: *mut ffi::usd_Attribute_t,
-}
-
-impl std::ops::Deref for AttributeRef {
-    type Target = Attribute;
-
-    fn deref(&self) -> &Self::Target {
-        unsafe { &*(self as *const AttributeRef as *const Attribute) }
-    }
-}
-
 pub struct Relationship {
     ptr: *mut ffi::usd_Relationship_t,
 }
@@ -602,6 +1966,71 @@ impl Relationship {
             }
         }
     }
+
+    pub fn forwarded_targets(&self) -> Option<sdf::PathVector> {
+        unsafe {
+            let targets = sdf::PathVector::default();
+            let mut result = false;
+            ffi::usd_Relationship_GetForwardedTargets(self.ptr, targets.ptr, &mut result);
+            if result {
+                Some(targets)
+            } else {
+                None
+            }
+        }
+    }
+
+    pub fn add_target<P>(&self, target: P) -> Result<(), Error>
+    where
+        P: TryInto<sdf::Path>,
+        Error: From<P::Error>,
+    {
+        let target: sdf::Path = target.try_into()?;
+        unsafe {
+            let position = ffi::usd_ListPosition::usd_ListPosition_UsdListPositionBackOfPrependList;
+            let mut result = false;
+            ffi::usd_Relationship_AddTarget(self.ptr, target.ptr, position, &mut result);
+            self.edit_result(result)
+        }
+    }
+
+    pub fn remove_target<P>(&self, target: P) -> Result<(), Error>
+    where
+        P: TryInto<sdf::Path>,
+        Error: From<P::Error>,
+    {
+        let target: sdf::Path = target.try_into()?;
+        unsafe {
+            let mut result = false;
+            ffi::usd_Relationship_RemoveTarget(self.ptr, target.ptr, &mut result);
+            self.edit_result(result)
+        }
+    }
+
+    pub fn set_targets(&self, targets: &[sdf::Path]) -> Result<(), Error> {
+        let targets = sdf::PathVector::from(targets);
+        unsafe {
+            let mut result = false;
+            ffi::usd_Relationship_SetTargets(self.ptr, targets.ptr, &mut result);
+            self.edit_result(result)
+        }
+    }
+
+    pub fn clear_targets(&self, remove_spec: bool) -> Result<(), Error> {
+        unsafe {
+            let mut result = false;
+            ffi::usd_Relationship_ClearTargets(self.ptr, remove_spec, &mut result);
+            self.edit_result(result)
+        }
+    }
+
+    fn edit_result(&self, result: bool) -> Result<(), Error> {
+        if result {
+            Ok(())
+        } else {
+            Err(Error::EditRelationship { path: self.path().text().to_string() })
+        }
+    }
 }
 
 impl Object for Relationship {
@@ -644,14 +2073,144 @@ impl std::ops::Deref for RelationshipRef {
     }
 }
 
+/// Types that can be read directly from an attribute with the typed
+/// `UsdAttribute::Get<T>`, without going through a `vt::Value`.
+pub trait AttributeValue {
+    #[doc(hidden)]
+    unsafe fn _get(
+        attr: *mut ffi::usd_Attribute_t,
+        value: &mut Self,
+        time: ffi::usd_TimeCode_t,
+    ) -> bool;
+}
+
+macro_rules! attribute_value {
+    (ptr $ty:ty, $get:ident) => {
+        impl AttributeValue for $ty {
+            unsafe fn _get(
+                attr: *mut ffi::usd_Attribute_t,
+                value: &mut Self,
+                time: ffi::usd_TimeCode_t,
+            ) -> bool {
+                let mut result = false;
+                ffi::$get(attr, value.ptr, time, &mut result);
+                result
+            }
+        }
+    };
+    ($ty:ty, $get:ident) => {
+        impl AttributeValue for $ty {
+            unsafe fn _get(
+                attr: *mut ffi::usd_Attribute_t,
+                value: &mut Self,
+                time: ffi::usd_TimeCode_t,
+            ) -> bool {
+                let mut result = false;
+                ffi::$get(attr, value as *mut $ty as *mut _, time, &mut result);
+                result
+            }
+        }
+    };
+}
+
+attribute_value!(i32, usd_Attribute_Get_int);
+attribute_value!(i64, usd_Attribute_Get_int64);
+attribute_value!(u32, usd_Attribute_Get_uint);
+attribute_value!(u8, usd_Attribute_Get_uchar);
+attribute_value!(gf::f16, usd_Attribute_Get_GfHalf);
+attribute_value!(f32, usd_Attribute_Get_float);
+attribute_value!(f64, usd_Attribute_Get_double);
+attribute_value!(bool, usd_Attribute_Get_bool);
+attribute_value!(glam::Vec2, usd_Attribute_Get_GfVec2f);
+attribute_value!(glam::Vec3, usd_Attribute_Get_GfVec3f);
+attribute_value!(glam::Vec4, usd_Attribute_Get_GfVec4f);
+attribute_value!(glam::DVec2, usd_Attribute_Get_GfVec2d);
+attribute_value!(glam::DVec3, usd_Attribute_Get_GfVec3d);
+attribute_value!(glam::DVec4, usd_Attribute_Get_GfVec4d);
+attribute_value!(gf::Vec2h, usd_Attribute_Get_GfVec2h);
+attribute_value!(gf::Vec3h, usd_Attribute_Get_GfVec3h);
+attribute_value!(gf::Vec4h, usd_Attribute_Get_GfVec4h);
+attribute_value!(glam::IVec2, usd_Attribute_Get_GfVec2i);
+attribute_value!(glam::IVec3, usd_Attribute_Get_GfVec3i);
+attribute_value!(glam::IVec4, usd_Attribute_Get_GfVec4i);
+attribute_value!(glam::Quat, usd_Attribute_Get_GfQuatf);
+attribute_value!(glam::DQuat, usd_Attribute_Get_GfQuatd);
+attribute_value!(gf::Quath, usd_Attribute_Get_GfQuath);
+attribute_value!(glam::DMat2, usd_Attribute_Get_GfMatrix2d);
+attribute_value!(glam::DMat3, usd_Attribute_Get_GfMatrix3d);
+attribute_value!(glam::DMat4, usd_Attribute_Get_GfMatrix4d);
+attribute_value!(sdf::TimeCode, usd_Attribute_Get_SdfTimeCode);
+attribute_value!(ptr tf::Token, usd_Attribute_Get_TfToken);
+attribute_value!(ptr vt::BoolArray, usd_Attribute_Get_VtBoolArray);
+attribute_value!(ptr vt::IntArray, usd_Attribute_Get_VtIntArray);
+attribute_value!(ptr vt::Int64Array, usd_Attribute_Get_VtInt64Array);
+attribute_value!(ptr vt::UIntArray, usd_Attribute_Get_VtUIntArray);
+attribute_value!(ptr vt::UCharArray, usd_Attribute_Get_VtUCharArray);
+attribute_value!(ptr vt::HalfArray, usd_Attribute_Get_VtHalfArray);
+attribute_value!(ptr vt::FloatArray, usd_Attribute_Get_VtFloatArray);
+attribute_value!(ptr vt::DoubleArray, usd_Attribute_Get_VtDoubleArray);
+attribute_value!(ptr vt::Vec2Array, usd_Attribute_Get_VtVec2fArray);
+attribute_value!(ptr vt::Vec3Array, usd_Attribute_Get_VtVec3fArray);
+attribute_value!(ptr vt::Vec4Array, usd_Attribute_Get_VtVec4fArray);
+attribute_value!(ptr vt::Vec2dArray, usd_Attribute_Get_VtVec2dArray);
+attribute_value!(ptr vt::Vec3dArray, usd_Attribute_Get_VtVec3dArray);
+attribute_value!(ptr vt::Vec4dArray, usd_Attribute_Get_VtVec4dArray);
+attribute_value!(ptr vt::Vec2hArray, usd_Attribute_Get_VtVec2hArray);
+attribute_value!(ptr vt::Vec3hArray, usd_Attribute_Get_VtVec3hArray);
+attribute_value!(ptr vt::Vec4hArray, usd_Attribute_Get_VtVec4hArray);
+attribute_value!(ptr vt::Vec2iArray, usd_Attribute_Get_VtVec2iArray);
+attribute_value!(ptr vt::Vec3iArray, usd_Attribute_Get_VtVec3iArray);
+attribute_value!(ptr vt::Vec4iArray, usd_Attribute_Get_VtVec4iArray);
+attribute_value!(ptr vt::QuatfArray, usd_Attribute_Get_VtQuatfArray);
+attribute_value!(ptr vt::QuatdArray, usd_Attribute_Get_VtQuatdArray);
+attribute_value!(ptr vt::QuathArray, usd_Attribute_Get_VtQuathArray);
+attribute_value!(ptr vt::Matrix2dArray, usd_Attribute_Get_VtMatrix2dArray);
+attribute_value!(ptr vt::Matrix3dArray, usd_Attribute_Get_VtMatrix3dArray);
+attribute_value!(ptr vt::Matrix4dArray, usd_Attribute_Get_VtMatrix4dArray);
+attribute_value!(ptr vt::TimeCodeArray, usd_Attribute_Get_SdfTimeCodeArray);
+attribute_value!(ptr vt::TokenArray, usd_Attribute_Get_VtTokenArray);
+attribute_value!(ptr vt::StringArray, usd_Attribute_Get_VtStringArray);
+attribute_value!(ptr vt::AssetPathArray, usd_Attribute_Get_SdfAssetPathArray);
+
 pub enum PropertyKind {
     Attribute(Attribute),
     Relationship(Relationship),
 }
 
 #[repr(transparent)]
+#[derive(Clone, Copy)]
 pub struct TimeCode(ffi::usd_TimeCode_t);
 
+impl TimeCode {
+    pub fn new(time: f64) -> Self {
+        TimeCode(ffi::usd_TimeCode_t { time })
+    }
+
+    pub fn earliest_time() -> Self {
+        unsafe {
+            let mut tc = ffi::usd_TimeCode_t { time: 0.0 };
+            ffi::usd_TimeCode_EarliestTime(&mut tc);
+            TimeCode(tc)
+        }
+    }
+
+    pub fn is_default(&self) -> bool {
+        self.0.time.is_nan()
+    }
+
+    pub fn is_earliest_time(&self) -> bool {
+        self.0.time == f64::MIN
+    }
+
+    pub fn is_numeric(&self) -> bool {
+        !self.is_default()
+    }
+
+    pub fn value(&self) -> f64 {
+        self.0.time
+    }
+}
+
 impl Default for TimeCode {
     fn default() -> Self {
         unsafe {
@@ -661,3 +2220,191 @@ impl Default for TimeCode {
         }
     }
 }
+
+impl From<f64> for TimeCode {
+    fn from(value: f64) -> Self {
+        TimeCode::new(value)
+    }
+}
+
+impl From<TimeCode> for f64 {
+    fn from(value: TimeCode) -> Self {
+        value.value()
+    }
+}
+
+// The default time is stored as NaN, so it is the only non-numeric value a
+// TimeCode can hold. Like UsdTimeCode, it compares equal to itself and orders
+// before every numeric time.
+impl PartialEq for TimeCode {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for TimeCode {}
+
+impl PartialOrd for TimeCode {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for TimeCode {
+    fn cmp(&self, other: &Self) -> Ordering {
+        match (self.is_default(), other.is_default()) {
+            (true, true) => Ordering::Equal,
+            (true, false) => Ordering::Less,
+            (false, true) => Ordering::Greater,
+            (false, false) => self.0.time.partial_cmp(&other.0.time).unwrap(),
+        }
+    }
+}
+
+impl Add<f64> for TimeCode {
+    type Output = TimeCode;
+
+    fn add(self, rhs: f64) -> Self::Output {
+        TimeCode::new(self.0.time + rhs)
+    }
+}
+
+impl Add<TimeCode> for TimeCode {
+    type Output = TimeCode;
+
+    fn add(self, rhs: TimeCode) -> Self::Output {
+        TimeCode::new(self.0.time + rhs.0.time)
+    }
+}
+
+impl AddAssign<f64> for TimeCode {
+    fn add_assign(&mut self, rhs: f64) {
+        self.0.time += rhs;
+    }
+}
+
+impl Sub<f64> for TimeCode {
+    type Output = TimeCode;
+
+    fn sub(self, rhs: f64) -> Self::Output {
+        TimeCode::new(self.0.time - rhs)
+    }
+}
+
+impl Sub<TimeCode> for TimeCode {
+    type Output = TimeCode;
+
+    fn sub(self, rhs: TimeCode) -> Self::Output {
+        TimeCode::new(self.0.time - rhs.0.time)
+    }
+}
+
+impl SubAssign<f64> for TimeCode {
+    fn sub_assign(&mut self, rhs: f64) {
+        self.0.time -= rhs;
+    }
+}
+
+impl Mul<f64> for TimeCode {
+    type Output = TimeCode;
+
+    fn mul(self, rhs: f64) -> Self::Output {
+        TimeCode::new(self.0.time * rhs)
+    }
+}
+
+impl Div<f64> for TimeCode {
+    type Output = TimeCode;
+
+    fn div(self, rhs: f64) -> Self::Output {
+        TimeCode::new(self.0.time / rhs)
+    }
+}
+
+impl fmt::Display for TimeCode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.is_default() {
+            write!(f, "DEFAULT")
+        } else if self.is_earliest_time() {
+            write!(f, "EARLIEST")
+        } else {
+            write!(f, "{}", self.0.time)
+        }
+    }
+}
+
+impl fmt::Debug for TimeCode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "TimeCode({})", self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn frames(start: f64, end: f64, step: f64) -> Vec<f64> {
+        FrameRange::new(start, end, step).map(f64::from).collect()
+    }
+
+    #[test]
+    fn frame_range_includes_end() {
+        assert_eq!(frames(1.0, 4.0, 1.0), vec![1.0, 2.0, 3.0, 4.0]);
+        assert_eq!(frames(0.0, 0.0, 1.0), vec![0.0]);
+        assert_eq!(FrameRange::new(1.0, 4.0, 1.0).len(), 4);
+    }
+
+    #[test]
+    fn frame_range_fractional_steps() {
+        let times = frames(0.0, 1.0, 0.1);
+        assert_eq!(times.len(), 11);
+        assert!((times[10] - 1.0).abs() < 1e-9);
+
+        let times = frames(0.0, 1000.0, 1.0 / 3.0);
+        assert_eq!(times.len(), 3001);
+
+        assert_eq!(frames(0.0, 1.0, 0.3).len(), 4);
+    }
+
+    #[test]
+    fn frame_range_empty() {
+        assert!(frames(4.0, 1.0, 1.0).is_empty());
+        assert!(frames(1.0, 4.0, 0.0).is_empty());
+        assert!(frames(1.0, 4.0, -1.0).is_empty());
+        assert!(frames(f64::NAN, 4.0, 1.0).is_empty());
+        assert!(frames(1.0, f64::NAN, 1.0).is_empty());
+        assert!(frames(1.0, 4.0, f64::NAN).is_empty());
+        assert_eq!(FrameRange::new(0.0, 1e20, 1.0).len(), 0);
+        assert_eq!(FrameRange::new(0.0, f64::MAX, f64::MIN_POSITIVE).len(), 0);
+        assert_eq!(FrameRange::empty().len(), 0);
+    }
+
+    #[test]
+    fn time_code_default_orders_before_numeric() {
+        let default = TimeCode::new(f64::NAN);
+        assert!(default.is_default());
+        assert_eq!(default, TimeCode::new(f64::NAN));
+        assert!(default < TimeCode::new(f64::MIN));
+        assert!(default < TimeCode::new(-1.0e9));
+        assert!(TimeCode::new(0.0) > default);
+
+        let mut times = vec![TimeCode::new(2.0), default, TimeCode::new(-1.0)];
+        times.sort();
+        assert!(times[0].is_default());
+        assert_eq!(times[1], TimeCode::new(-1.0));
+        assert_eq!(times[2], TimeCode::new(2.0));
+    }
+
+    #[test]
+    fn time_code_arithmetic() {
+        let mut time = TimeCode::new(10.0) + 2.5;
+        assert_eq!(time.value(), 12.5);
+        assert_eq!((time - TimeCode::new(2.5)).value(), 10.0);
+        assert_eq!((time * 2.0).value(), 25.0);
+        assert_eq!((time / 2.0).value(), 6.25);
+        time -= 0.5;
+        assert_eq!(f64::from(time), 12.0);
+        time += 1.0;
+        assert_eq!(time, TimeCode::from(13.0));
+    }
+}
@@ -1,13 +1,41 @@
-use std::ops::IndexMut;
+use crate::{cpp, ffi, gf, sdf, tf};
+use glam::{
+    DMat2, DMat3, DMat4, DQuat, DVec2, DVec3, DVec4, IVec2, IVec3, IVec4, Quat, Vec2, Vec3, Vec4,
+};
+use half::f16;
+use std::ffi::{CStr, CString};
+use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::marker::PhantomData;
+
+#[derive(Debug)]
+pub enum Error {
+    Cast { from: String, to: &'static str },
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Cast { from, to } => write!(f, "cannot cast value of type {} to {}", from, to),
+        }
+    }
+}
 
-use crate::{ffi, sdf, tf};
-use glam::{Vec2, Vec3, Vec4};
+impl std::error::Error for Error {}
 
 pub struct TokenArray {
     pub(crate) ptr: *mut ffi::vt_TokenArray_t,
 }
 
 impl TokenArray {
+    pub fn new() -> Self {
+        unsafe {
+            let mut ptr = std::ptr::null_mut();
+            ffi::vt_TokenArray_default(&mut ptr);
+            TokenArray { ptr }
+        }
+    }
+
     pub fn size(&self) -> usize {
         unsafe {
             let mut result = 0;
@@ -16,11 +44,11 @@ impl TokenArray {
         }
     }
 
-    pub fn at(&self, index: usize) -> tf::TokenRef {
+    pub fn at(&self, index: usize) -> tf::TokenRef<'_> {
         unsafe {
             let mut ptr = std::ptr::null_mut();
             ffi::vt_TokenArray_op_index(self.ptr, index, &mut ptr);
-            tf::TokenRef { ptr }
+            tf::TokenRef::_from_ptr(ptr)
         }
     }
 
@@ -31,10 +59,80 @@ impl TokenArray {
             end: self.size(),
         }
     }
+
+    pub fn push(&mut self, token: &tf::Token) {
+        unsafe {
+            ffi::vt_TokenArray_push_back(self.ptr, token.ptr);
+        }
+    }
+
+    pub fn resize(&mut self, new_len: usize, value: &tf::Token) {
+        let len = self.size();
+        unsafe {
+            ffi::vt_TokenArray_resize(self.ptr, new_len);
+        }
+
+        for index in len..new_len {
+            self.set(index, value);
+        }
+    }
+
+    /// Replace the token at `index`. If the underlying storage is shared with
+    /// another array it is copied first.
+    ///
+    /// This stands in for the `as_mut_slice()` the other array types have:
+    /// `tf::Token` wraps a pointer to a TfToken rather than holding one, so the
+    /// array's storage can't be viewed as a `[tf::Token]`.
+    pub fn set(&mut self, index: usize, token: &tf::Token) {
+        assert!(index < self.size(), "index out of bounds");
+        unsafe {
+            ffi::vt_TokenArray_set(self.ptr, index, token.ptr);
+        }
+    }
+}
+
+impl Default for TokenArray {
+    fn default() -> Self {
+        TokenArray::new()
+    }
+}
+
+impl Drop for TokenArray {
+    fn drop(&mut self) {
+        unsafe {
+            ffi::vt_TokenArray_dtor(self.ptr);
+        }
+    }
+}
+
+impl From<&[tf::Token]> for TokenArray {
+    fn from(value: &[tf::Token]) -> Self {
+        let mut array = TokenArray::new();
+        for token in value {
+            array.push(token);
+        }
+        array
+    }
+}
+
+impl From<Vec<tf::Token>> for TokenArray {
+    fn from(value: Vec<tf::Token>) -> Self {
+        TokenArray::from(value.as_slice())
+    }
+}
+
+impl FromIterator<tf::Token> for TokenArray {
+    fn from_iter<T: IntoIterator<Item = tf::Token>>(iter: T) -> Self {
+        let mut array = TokenArray::new();
+        for token in iter {
+            array.push(&token);
+        }
+        array
+    }
 }
 
 impl<'a> IntoIterator for &'a TokenArray {
-    type Item = tf::TokenRef;
+    type Item = tf::TokenRef<'a>;
     type IntoIter = TokenArrayIterator<'a>;
 
     fn into_iter(self) -> Self::IntoIter {
@@ -49,7 +147,7 @@ pub struct TokenArrayIterator<'a> {
 }
 
 impl<'a> Iterator for TokenArrayIterator<'a> {
-    type Item = tf::TokenRef;
+    type Item = tf::TokenRef<'a>;
 
     fn next(&mut self) -> Option<Self::Item> {
         if self.current == self.end {
@@ -60,12 +158,27 @@ impl<'a> Iterator for TokenArrayIterator<'a> {
             Some(self.vec.at(cur))
         }
     }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.end - self.current;
+        (remaining, Some(remaining))
+    }
 }
 
+impl<'a> ExactSizeIterator for TokenArrayIterator<'a> {}
+
 pub struct TokenArrayRef {
     pub(crate) ptr: *mut ffi::vt_TokenArray_t,
 }
 
+impl TokenArrayRef {
+    unsafe fn _from_value(value: &Value) -> Self {
+        let mut ptr = std::ptr::null_mut();
+        ffi::vt_Value_Get_VtTokenArray(value.ptr, &mut ptr);
+        TokenArrayRef { ptr }
+    }
+}
+
 impl std::ops::Deref for TokenArrayRef {
     type Target = TokenArray;
 
@@ -74,124 +187,138 @@ impl std::ops::Deref for TokenArrayRef {
     }
 }
 
-pub struct IntArray {
-    pub(crate) ptr: *mut ffi::vt_IntArray_t,
+pub struct StringArray {
+    pub(crate) ptr: *mut ffi::vt_StringArray_t,
 }
 
-impl IntArray {
+impl StringArray {
+    pub fn new() -> Self {
+        unsafe {
+            let mut ptr = std::ptr::null_mut();
+            ffi::vt_StringArray_default(&mut ptr);
+            StringArray { ptr }
+        }
+    }
+
     pub fn size(&self) -> usize {
         unsafe {
             let mut result = 0;
-            ffi::vt_IntArray_size(self.ptr, &mut result);
+            ffi::vt_StringArray_size(self.ptr, &mut result);
             result
         }
     }
 
-    pub fn at(&self, index: usize) -> &i32 {
+    pub fn at(&self, index: usize) -> cpp::StringRef<'_> {
         unsafe {
             let mut ptr = std::ptr::null_mut();
-            ffi::vt_IntArray_op_index(self.ptr, index, &mut ptr);
-            &*(ptr as *mut i32)
+            ffi::vt_StringArray_op_index(self.ptr, index, &mut ptr);
+            cpp::StringRef::_from_ptr(ptr)
         }
     }
 
-    pub fn iter(&self) -> IntArrayIterator {
-        IntArrayIterator {
+    pub fn iter(&self) -> StringArrayIterator {
+        StringArrayIterator {
             vec: self,
             current: 0,
             end: self.size(),
         }
     }
-}
 
-impl<'a> IntoIterator for &'a IntArray {
-    type Item = &'a i32;
-    type IntoIter = IntArrayIterator<'a>;
-
-    fn into_iter(self) -> Self::IntoIter {
-        self.iter()
+    /// Panics if `value` contains an interior nul byte, like `tf::Token::new`.
+    pub fn push(&mut self, value: &str) {
+        let value = CString::new(value).unwrap();
+        unsafe {
+            ffi::vt_StringArray_push_back(self.ptr, value.as_ptr() as *mut i8);
+        }
     }
-}
 
-pub struct IntArrayIterator<'a> {
-    vec: &'a IntArray,
-    current: usize,
-    end: usize,
-}
-
-impl<'a> Iterator for IntArrayIterator<'a> {
-    type Item = &'a i32;
-
-    fn next(&mut self) -> Option<Self::Item> {
-        if self.current == self.end {
-            None
+    /// Panics if `value` contains an interior nul byte and the array grows.
+    pub fn resize(&mut self, new_len: usize, value: &str) {
+        let len = self.size();
+        if new_len <= len {
+            unsafe {
+                ffi::vt_StringArray_resize(self.ptr, new_len);
+            }
         } else {
-            let cur = self.current;
-            self.current += 1;
-            Some(self.vec.at(cur))
+            let value = CString::new(value).unwrap();
+            for _ in len..new_len {
+                unsafe {
+                    ffi::vt_StringArray_push_back(self.ptr, value.as_ptr() as *mut i8);
+                }
+            }
         }
     }
 }
 
-pub struct IntArrayRef {
-    pub(crate) ptr: *mut ffi::vt_IntArray_t,
+impl Default for StringArray {
+    fn default() -> Self {
+        StringArray::new()
+    }
 }
 
-impl std::ops::Deref for IntArrayRef {
-    type Target = IntArray;
+impl Drop for StringArray {
+    fn drop(&mut self) {
+        unsafe {
+            ffi::vt_StringArray_dtor(self.ptr);
+        }
+    }
+}
 
-    fn deref(&self) -> &Self::Target {
-        unsafe { &*(self as *const IntArrayRef as *const IntArray) }
+/// Panics if any element contains an interior nul byte.
+impl<S: AsRef<str>> From<&[S]> for StringArray {
+    fn from(value: &[S]) -> Self {
+        let mut array = StringArray::new();
+        for s in value {
+            array.push(s.as_ref());
+        }
+        array
     }
 }
 
-pub struct FloatArray {
-    pub(crate) ptr: *mut ffi::vt_FloatArray_t,
+impl<S: AsRef<str>> From<Vec<S>> for StringArray {
+    fn from(value: Vec<S>) -> Self {
+        StringArray::from(value.as_slice())
+    }
 }
 
-impl FloatArray {
-    pub fn size(&self) -> usize {
-        unsafe {
-            let mut result = 0;
-            ffi::vt_FloatArray_size(self.ptr, &mut result);
-            result
+/// Panics if any element contains an interior nul byte.
+impl<S: AsRef<str>> FromIterator<S> for StringArray {
+    fn from_iter<T: IntoIterator<Item = S>>(iter: T) -> Self {
+        let mut array = StringArray::new();
+        for s in iter {
+            array.push(s.as_ref());
         }
+        array
     }
+}
 
-    pub fn at(&self, index: usize) -> &f32 {
+impl ToValue for StringArray {
+    fn to_value(&self) -> Value {
         unsafe {
             let mut ptr = std::ptr::null_mut();
-            ffi::vt_FloatArray_op_index(self.ptr, index, &mut ptr);
-            &*(ptr as *mut f32)
-        }
-    }
-
-    pub fn iter(&self) -> FloatArrayIterator {
-        FloatArrayIterator {
-            vec: self,
-            current: 0,
-            end: self.size(),
+            ffi::vt_Value_from_VtStringArray(self.ptr, &mut ptr);
+            Value { ptr }
         }
     }
 }
 
-impl<'a> IntoIterator for &'a FloatArray {
-    type Item = &'a f32;
-    type IntoIter = FloatArrayIterator<'a>;
+impl<'a> IntoIterator for &'a StringArray {
+    type Item = cpp::StringRef<'a>;
+    type IntoIter = StringArrayIterator<'a>;
 
     fn into_iter(self) -> Self::IntoIter {
         self.iter()
     }
 }
 
-pub struct FloatArrayIterator<'a> {
-    vec: &'a FloatArray,
+pub struct StringArrayIterator<'a> {
+    vec: &'a StringArray,
     current: usize,
     end: usize,
 }
 
-impl<'a> Iterator for FloatArrayIterator<'a> {
-    type Item = &'a f32;
+impl<'a> Iterator for StringArrayIterator<'a> {
+    type Item = cpp::StringRef<'a>;
 
     fn next(&mut self) -> Option<Self::Item> {
         if self.current == self.end {
@@ -202,138 +329,159 @@ impl<'a> Iterator for FloatArrayIterator<'a> {
             Some(self.vec.at(cur))
         }
     }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.end - self.current;
+        (remaining, Some(remaining))
+    }
+}
+
+impl<'a> ExactSizeIterator for StringArrayIterator<'a> {}
+
+pub struct StringArrayRef {
+    pub(crate) ptr: *mut ffi::vt_StringArray_t,
 }
 
-pub struct FloatArrayRef {
-    pub(crate) ptr: *mut ffi::vt_FloatArray_t,
+impl StringArrayRef {
+    unsafe fn _from_value(value: &Value) -> Self {
+        let mut ptr = std::ptr::null_mut();
+        ffi::vt_Value_Get_VtStringArray(value.ptr, &mut ptr);
+        StringArrayRef { ptr }
+    }
 }
 
-impl std::ops::Deref for FloatArrayRef {
-    type Target = FloatArray;
+impl std::ops::Deref for StringArrayRef {
+    type Target = StringArray;
 
     fn deref(&self) -> &Self::Target {
-        unsafe { &*(self as *const FloatArrayRef as *const FloatArray) }
+        unsafe { &*(self as *const StringArrayRef as *const StringArray) }
     }
 }
 
-pub struct DoubleArray {
-    pub(crate) ptr: *mut ffi::vt_DoubleArray_t,
+pub struct AssetPathArray {
+    pub(crate) ptr: *mut ffi::sdf_AssetPathArray_t,
 }
 
-impl DoubleArray {
+impl AssetPathArray {
+    pub fn new() -> Self {
+        unsafe {
+            let mut ptr = std::ptr::null_mut();
+            ffi::sdf_AssetPathArray_default(&mut ptr);
+            AssetPathArray { ptr }
+        }
+    }
+
     pub fn size(&self) -> usize {
         unsafe {
             let mut result = 0;
-            ffi::vt_DoubleArray_size(self.ptr, &mut result);
+            ffi::sdf_AssetPathArray_size(self.ptr, &mut result);
             result
         }
     }
 
-    pub fn at(&self, index: usize) -> &f32 {
+    pub fn at(&self, index: usize) -> sdf::AssetPathRef<'_> {
         unsafe {
             let mut ptr = std::ptr::null_mut();
-            ffi::vt_DoubleArray_op_index(self.ptr, index, &mut ptr);
-            &*(ptr as *mut f32)
+            ffi::sdf_AssetPathArray_op_index(self.ptr, index, &mut ptr);
+            sdf::AssetPathRef::_from_ptr(ptr)
         }
     }
 
-    pub fn iter(&self) -> DoubleArrayIterator {
-        DoubleArrayIterator {
+    pub fn iter(&self) -> AssetPathArrayIterator {
+        AssetPathArrayIterator {
             vec: self,
             current: 0,
             end: self.size(),
         }
     }
-}
-
-impl<'a> IntoIterator for &'a DoubleArray {
-    type Item = &'a f32;
-    type IntoIter = DoubleArrayIterator<'a>;
 
-    fn into_iter(self) -> Self::IntoIter {
-        self.iter()
+    pub fn push(&mut self, value: &sdf::AssetPath) {
+        unsafe {
+            ffi::sdf_AssetPathArray_push_back(self.ptr, value.ptr);
+        }
     }
-}
-
-pub struct DoubleArrayIterator<'a> {
-    vec: &'a DoubleArray,
-    current: usize,
-    end: usize,
-}
-
-impl<'a> Iterator for DoubleArrayIterator<'a> {
-    type Item = &'a f32;
 
-    fn next(&mut self) -> Option<Self::Item> {
-        if self.current == self.end {
-            None
+    pub fn resize(&mut self, new_len: usize, value: &sdf::AssetPath) {
+        let len = self.size();
+        if new_len <= len {
+            unsafe {
+                ffi::sdf_AssetPathArray_resize(self.ptr, new_len);
+            }
         } else {
-            let cur = self.current;
-            self.current += 1;
-            Some(self.vec.at(cur))
+            for _ in len..new_len {
+                self.push(value);
+            }
         }
     }
 }
 
-pub struct DoubleArrayRef {
-    pub(crate) ptr: *mut ffi::vt_DoubleArray_t,
+impl Default for AssetPathArray {
+    fn default() -> Self {
+        AssetPathArray::new()
+    }
 }
 
-impl std::ops::Deref for DoubleArrayRef {
-    type Target = DoubleArray;
+impl Drop for AssetPathArray {
+    fn drop(&mut self) {
+        unsafe {
+            ffi::sdf_AssetPathArray_dtor(self.ptr);
+        }
+    }
+}
 
-    fn deref(&self) -> &Self::Target {
-        unsafe { &*(self as *const DoubleArrayRef as *const DoubleArray) }
+impl From<&[sdf::AssetPath]> for AssetPathArray {
+    fn from(value: &[sdf::AssetPath]) -> Self {
+        let mut array = AssetPathArray::new();
+        for asset_path in value {
+            array.push(asset_path);
+        }
+        array
     }
 }
 
-pub struct Vec2Array {
-    pub(crate) ptr: *mut ffi::gf_Vec2fArray_t,
+impl From<Vec<sdf::AssetPath>> for AssetPathArray {
+    fn from(value: Vec<sdf::AssetPath>) -> Self {
+        AssetPathArray::from(value.as_slice())
+    }
 }
 
-impl Vec2Array {
-    pub fn size(&self) -> usize {
-        unsafe {
-            let mut result = 0;
-            ffi::gf_Vec2fArray_size(self.ptr, &mut result);
-            result
+impl FromIterator<sdf::AssetPath> for AssetPathArray {
+    fn from_iter<T: IntoIterator<Item = sdf::AssetPath>>(iter: T) -> Self {
+        let mut array = AssetPathArray::new();
+        for asset_path in iter {
+            array.push(&asset_path);
         }
+        array
     }
+}
 
-    pub fn at(&self, index: usize) -> &Vec2 {
+impl ToValue for AssetPathArray {
+    fn to_value(&self) -> Value {
         unsafe {
             let mut ptr = std::ptr::null_mut();
-            ffi::gf_Vec2fArray_op_index(self.ptr, index, &mut ptr);
-            &*(ptr as *mut Vec2)
-        }
-    }
-
-    pub fn iter(&self) -> Vec2ArrayIterator {
-        Vec2ArrayIterator {
-            vec: self,
-            current: 0,
-            end: self.size(),
+            ffi::vt_Value_from_SdfAssetPathArray(self.ptr, &mut ptr);
+            Value { ptr }
         }
     }
 }
 
-impl<'a> IntoIterator for &'a Vec2Array {
-    type Item = &'a Vec2;
-    type IntoIter = Vec2ArrayIterator<'a>;
+impl<'a> IntoIterator for &'a AssetPathArray {
+    type Item = sdf::AssetPathRef<'a>;
+    type IntoIter = AssetPathArrayIterator<'a>;
 
     fn into_iter(self) -> Self::IntoIter {
         self.iter()
     }
 }
 
-pub struct Vec2ArrayIterator<'a> {
-    vec: &'a Vec2Array,
+pub struct AssetPathArrayIterator<'a> {
+    vec: &'a AssetPathArray,
     current: usize,
     end: usize,
 }
 
-impl<'a> Iterator for Vec2ArrayIterator<'a> {
-    type Item = &'a Vec2;
+impl<'a> Iterator for AssetPathArrayIterator<'a> {
+    type Item = sdf::AssetPathRef<'a>;
 
     fn next(&mut self) -> Option<Self::Item> {
         if self.current == self.end {
@@ -344,162 +492,1141 @@ impl<'a> Iterator for Vec2ArrayIterator<'a> {
             Some(self.vec.at(cur))
         }
     }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.end - self.current;
+        (remaining, Some(remaining))
+    }
+}
+
+impl<'a> ExactSizeIterator for AssetPathArrayIterator<'a> {}
+
+pub struct AssetPathArrayRef {
+    pub(crate) ptr: *mut ffi::sdf_AssetPathArray_t,
 }
 
-pub struct Vec2ArrayRef {
-    pub(crate) ptr: *mut ffi::gf_Vec2fArray_t,
+impl AssetPathArrayRef {
+    unsafe fn _from_value(value: &Value) -> Self {
+        let mut ptr = std::ptr::null_mut();
+        ffi::vt_Value_Get_SdfAssetPathArray(value.ptr, &mut ptr);
+        AssetPathArrayRef { ptr }
+    }
 }
 
-impl std::ops::Deref for Vec2ArrayRef {
-    type Target = Vec2Array;
+impl std::ops::Deref for AssetPathArrayRef {
+    type Target = AssetPathArray;
 
     fn deref(&self) -> &Self::Target {
-        unsafe { &*(self as *const Vec2ArrayRef as *const Vec2Array) }
+        unsafe { &*(self as *const AssetPathArrayRef as *const AssetPathArray) }
     }
 }
 
-pub struct Vec3Array {
-    pub(crate) ptr: *mut ffi::gf_Vec3fArray_t,
+macro_rules! array_type {
+    (
+        $name:ident,
+        $ref_name:ident,
+        $iterator:ident,
+        $elem:ty,
+        $ffi_ty:ident,
+        $default:ident,
+        $dtor:ident,
+        $copy:ident,
+        $size:ident,
+        $cdata:ident,
+        $data:ident,
+        $push_back:ident,
+        $resize:ident,
+        $as_array:ident,
+        $is_holding:ident,
+        $get:ident,
+        $from:ident,
+        $can_cast:ident,
+        $cast:ident,
+        $type_name:literal
+    ) => {
+        pub struct $name {
+            pub(crate) ptr: *mut ffi::$ffi_ty,
+        }
+
+        impl $name {
+            pub fn new() -> Self {
+                unsafe {
+                    let mut ptr = std::ptr::null_mut();
+                    ffi::$default(&mut ptr);
+                    $name { ptr }
+                }
+            }
+
+            pub fn size(&self) -> usize {
+                unsafe {
+                    let mut result = 0;
+                    ffi::$size(self.ptr, &mut result);
+                    result
+                }
+            }
+
+            pub fn at(&self, index: usize) -> &$elem {
+                &self.as_slice()[index]
+            }
+
+            pub fn as_slice(&self) -> &[$elem] {
+                unsafe {
+                    let size = self.size();
+                    if size == 0 {
+                        return &[];
+                    }
+
+                    let mut ptr = std::ptr::null_mut();
+                    ffi::$cdata(self.ptr, &mut ptr);
+                    std::slice::from_raw_parts(ptr as *const $elem, size)
+                }
+            }
+
+            /// Get a mutable view of the elements. If the underlying storage is
+            /// shared with another array it is copied first.
+            pub fn as_mut_slice(&mut self) -> &mut [$elem] {
+                unsafe {
+                    let size = self.size();
+                    if size == 0 {
+                        return &mut [];
+                    }
+
+                    let mut ptr = std::ptr::null_mut();
+                    ffi::$data(self.ptr, &mut ptr);
+                    std::slice::from_raw_parts_mut(ptr as *mut $elem, size)
+                }
+            }
+
+            pub fn iter(&self) -> $iterator {
+                $iterator {
+                    iter: self.as_slice().iter(),
+                }
+            }
+
+            pub fn push(&mut self, value: $elem) {
+                unsafe {
+                    ffi::$push_back(self.ptr, &value as *const $elem as *mut _);
+                }
+            }
+
+            pub fn resize(&mut self, new_len: usize, value: $elem) {
+                let len = self.size();
+                unsafe {
+                    ffi::$resize(self.ptr, new_len);
+                }
+
+                if new_len > len {
+                    self.as_mut_slice()[len..].fill(value);
+                }
+            }
+        }
+
+        impl Default for $name {
+            fn default() -> Self {
+                $name::new()
+            }
+        }
+
+        impl Drop for $name {
+            fn drop(&mut self) {
+                unsafe {
+                    ffi::$dtor(self.ptr);
+                }
+            }
+        }
+
+        // VtArray copies share their storage until one of them is written to
+        impl Clone for $name {
+            fn clone(&self) -> Self {
+                unsafe {
+                    let mut ptr = std::ptr::null_mut();
+                    ffi::$copy(self.ptr, &mut ptr);
+                    $name { ptr }
+                }
+            }
+        }
+
+        impl From<&[$elem]> for $name {
+            fn from(value: &[$elem]) -> Self {
+                let mut array = $name::new();
+                unsafe {
+                    ffi::$resize(array.ptr, value.len());
+                }
+                array.as_mut_slice().copy_from_slice(value);
+                array
+            }
+        }
+
+        impl From<Vec<$elem>> for $name {
+            fn from(value: Vec<$elem>) -> Self {
+                $name::from(value.as_slice())
+            }
+        }
+
+        impl FromIterator<$elem> for $name {
+            fn from_iter<T: IntoIterator<Item = $elem>>(iter: T) -> Self {
+                let values: Vec<$elem> = iter.into_iter().collect();
+                $name::from(values.as_slice())
+            }
+        }
+
+        impl std::ops::Deref for $name {
+            type Target = [$elem];
+
+            fn deref(&self) -> &Self::Target {
+                self.as_slice()
+            }
+        }
+
+        impl<I: std::slice::SliceIndex<[$elem]>> std::ops::Index<I> for $name {
+            type Output = I::Output;
+
+            fn index(&self, index: I) -> &Self::Output {
+                &self.as_slice()[index]
+            }
+        }
+
+        impl<'a> IntoIterator for &'a $name {
+            type Item = &'a $elem;
+            type IntoIter = $iterator<'a>;
+
+            fn into_iter(self) -> Self::IntoIter {
+                self.iter()
+            }
+        }
+
+        pub struct $iterator<'a> {
+            iter: std::slice::Iter<'a, $elem>,
+        }
+
+        impl<'a> Iterator for $iterator<'a> {
+            type Item = &'a $elem;
+
+            fn next(&mut self) -> Option<Self::Item> {
+                self.iter.next()
+            }
+
+            fn size_hint(&self) -> (usize, Option<usize>) {
+                self.iter.size_hint()
+            }
+        }
+
+        impl<'a> DoubleEndedIterator for $iterator<'a> {
+            fn next_back(&mut self) -> Option<Self::Item> {
+                self.iter.next_back()
+            }
+        }
+
+        impl<'a> ExactSizeIterator for $iterator<'a> {}
+
+        pub struct $ref_name {
+            pub(crate) ptr: *mut ffi::$ffi_ty,
+        }
+
+        impl $ref_name {
+            unsafe fn _from_value(value: &Value) -> Self {
+                let mut ptr = std::ptr::null_mut();
+                ffi::$get(value.ptr, &mut ptr);
+                $ref_name { ptr }
+            }
+        }
+
+        impl std::ops::Deref for $ref_name {
+            type Target = $name;
+
+            fn deref(&self) -> &Self::Target {
+                unsafe { &*(self as *const $ref_name as *const $name) }
+            }
+        }
+
+        impl ToValue for $name {
+            fn to_value(&self) -> Value {
+                unsafe {
+                    let mut ptr = std::ptr::null_mut();
+                    ffi::$from(self.ptr, &mut ptr);
+                    Value { ptr }
+                }
+            }
+        }
+
+        impl ValueCast for $name {
+            const TYPE_NAME: &'static str = $type_name;
+
+            fn can_cast(value: &Value) -> bool {
+                unsafe {
+                    let mut result = false;
+                    ffi::$can_cast(value.ptr, &mut result);
+                    result
+                }
+            }
+
+            fn cast(value: &Value) -> Option<Self> {
+                let value = unsafe {
+                    let mut ptr = std::ptr::null_mut();
+                    ffi::$cast(value.ptr, &mut ptr);
+                    Value { ptr }
+                };
+                value.$as_array().map(|array| $name::clone(&array))
+            }
+        }
+
+        impl Value {
+            pub fn $as_array(&self) -> Option<$ref_name> {
+                unsafe {
+                    let mut is_holding = false;
+                    ffi::$is_holding(self.ptr, &mut is_holding);
+                    if is_holding {
+                        Some($ref_name::_from_value(self))
+                    } else {
+                        None
+                    }
+                }
+            }
+        }
+    };
+}
+
+array_type!(
+    BoolArray,
+    BoolArrayRef,
+    BoolArrayIterator,
+    bool,
+    vt_BoolArray_t,
+    vt_BoolArray_default,
+    vt_BoolArray_dtor,
+    vt_BoolArray_copy,
+    vt_BoolArray_size,
+    vt_BoolArray_cdata,
+    vt_BoolArray_data,
+    vt_BoolArray_push_back,
+    vt_BoolArray_resize,
+    as_bool_array,
+    vt_Value_IsHolding_VtBoolArray,
+    vt_Value_Get_VtBoolArray,
+    vt_Value_from_VtBoolArray,
+    vt_Value_CanCast_VtBoolArray,
+    vt_Value_Cast_VtBoolArray,
+    "bool[]"
+);
+
+array_type!(
+    IntArray,
+    IntArrayRef,
+    IntArrayIterator,
+    i32,
+    vt_IntArray_t,
+    vt_IntArray_default,
+    vt_IntArray_dtor,
+    vt_IntArray_copy,
+    vt_IntArray_size,
+    vt_IntArray_cdata,
+    vt_IntArray_data,
+    vt_IntArray_push_back,
+    vt_IntArray_resize,
+    as_int_array,
+    vt_Value_IsHolding_VtIntArray,
+    vt_Value_Get_VtIntArray,
+    vt_Value_from_VtIntArray,
+    vt_Value_CanCast_VtIntArray,
+    vt_Value_Cast_VtIntArray,
+    "int[]"
+);
+
+array_type!(
+    Int64Array,
+    Int64ArrayRef,
+    Int64ArrayIterator,
+    i64,
+    vt_Int64Array_t,
+    vt_Int64Array_default,
+    vt_Int64Array_dtor,
+    vt_Int64Array_copy,
+    vt_Int64Array_size,
+    vt_Int64Array_cdata,
+    vt_Int64Array_data,
+    vt_Int64Array_push_back,
+    vt_Int64Array_resize,
+    as_int64_array,
+    vt_Value_IsHolding_VtInt64Array,
+    vt_Value_Get_VtInt64Array,
+    vt_Value_from_VtInt64Array,
+    vt_Value_CanCast_VtInt64Array,
+    vt_Value_Cast_VtInt64Array,
+    "int64[]"
+);
+
+array_type!(
+    UIntArray,
+    UIntArrayRef,
+    UIntArrayIterator,
+    u32,
+    vt_UIntArray_t,
+    vt_UIntArray_default,
+    vt_UIntArray_dtor,
+    vt_UIntArray_copy,
+    vt_UIntArray_size,
+    vt_UIntArray_cdata,
+    vt_UIntArray_data,
+    vt_UIntArray_push_back,
+    vt_UIntArray_resize,
+    as_uint_array,
+    vt_Value_IsHolding_VtUIntArray,
+    vt_Value_Get_VtUIntArray,
+    vt_Value_from_VtUIntArray,
+    vt_Value_CanCast_VtUIntArray,
+    vt_Value_Cast_VtUIntArray,
+    "uint[]"
+);
+
+array_type!(
+    UCharArray,
+    UCharArrayRef,
+    UCharArrayIterator,
+    u8,
+    vt_UCharArray_t,
+    vt_UCharArray_default,
+    vt_UCharArray_dtor,
+    vt_UCharArray_copy,
+    vt_UCharArray_size,
+    vt_UCharArray_cdata,
+    vt_UCharArray_data,
+    vt_UCharArray_push_back,
+    vt_UCharArray_resize,
+    as_uchar_array,
+    vt_Value_IsHolding_VtUCharArray,
+    vt_Value_Get_VtUCharArray,
+    vt_Value_from_VtUCharArray,
+    vt_Value_CanCast_VtUCharArray,
+    vt_Value_Cast_VtUCharArray,
+    "uchar[]"
+);
+
+array_type!(
+    HalfArray,
+    HalfArrayRef,
+    HalfArrayIterator,
+    f16,
+    vt_HalfArray_t,
+    vt_HalfArray_default,
+    vt_HalfArray_dtor,
+    vt_HalfArray_copy,
+    vt_HalfArray_size,
+    vt_HalfArray_cdata,
+    vt_HalfArray_data,
+    vt_HalfArray_push_back,
+    vt_HalfArray_resize,
+    as_half_array,
+    vt_Value_IsHolding_VtHalfArray,
+    vt_Value_Get_VtHalfArray,
+    vt_Value_from_VtHalfArray,
+    vt_Value_CanCast_VtHalfArray,
+    vt_Value_Cast_VtHalfArray,
+    "half[]"
+);
+
+array_type!(
+    FloatArray,
+    FloatArrayRef,
+    FloatArrayIterator,
+    f32,
+    vt_FloatArray_t,
+    vt_FloatArray_default,
+    vt_FloatArray_dtor,
+    vt_FloatArray_copy,
+    vt_FloatArray_size,
+    vt_FloatArray_cdata,
+    vt_FloatArray_data,
+    vt_FloatArray_push_back,
+    vt_FloatArray_resize,
+    as_float_array,
+    vt_Value_IsHolding_VtFloatArray,
+    vt_Value_Get_VtFloatArray,
+    vt_Value_from_VtFloatArray,
+    vt_Value_CanCast_VtFloatArray,
+    vt_Value_Cast_VtFloatArray,
+    "float[]"
+);
+
+array_type!(
+    DoubleArray,
+    DoubleArrayRef,
+    DoubleArrayIterator,
+    f64,
+    vt_DoubleArray_t,
+    vt_DoubleArray_default,
+    vt_DoubleArray_dtor,
+    vt_DoubleArray_copy,
+    vt_DoubleArray_size,
+    vt_DoubleArray_cdata,
+    vt_DoubleArray_data,
+    vt_DoubleArray_push_back,
+    vt_DoubleArray_resize,
+    as_double_array,
+    vt_Value_IsHolding_VtDoubleArray,
+    vt_Value_Get_VtDoubleArray,
+    vt_Value_from_VtDoubleArray,
+    vt_Value_CanCast_VtDoubleArray,
+    vt_Value_Cast_VtDoubleArray,
+    "double[]"
+);
+
+array_type!(
+    Vec2Array,
+    Vec2ArrayRef,
+    Vec2ArrayIterator,
+    Vec2,
+    gf_Vec2fArray_t,
+    gf_Vec2fArray_default,
+    gf_Vec2fArray_dtor,
+    gf_Vec2fArray_copy,
+    gf_Vec2fArray_size,
+    gf_Vec2fArray_cdata,
+    gf_Vec2fArray_data,
+    gf_Vec2fArray_push_back,
+    gf_Vec2fArray_resize,
+    as_vec2_array,
+    vt_Value_IsHolding_VtVec2fArray,
+    vt_Value_Get_VtVec2fArray,
+    vt_Value_from_VtVec2fArray,
+    vt_Value_CanCast_VtVec2fArray,
+    vt_Value_Cast_VtVec2fArray,
+    "float2[]"
+);
+
+array_type!(
+    Vec3Array,
+    Vec3ArrayRef,
+    Vec3ArrayIterator,
+    Vec3,
+    gf_Vec3fArray_t,
+    gf_Vec3fArray_default,
+    gf_Vec3fArray_dtor,
+    gf_Vec3fArray_copy,
+    gf_Vec3fArray_size,
+    gf_Vec3fArray_cdata,
+    gf_Vec3fArray_data,
+    gf_Vec3fArray_push_back,
+    gf_Vec3fArray_resize,
+    as_vec3_array,
+    vt_Value_IsHolding_VtVec3fArray,
+    vt_Value_Get_VtVec3fArray,
+    vt_Value_from_VtVec3fArray,
+    vt_Value_CanCast_VtVec3fArray,
+    vt_Value_Cast_VtVec3fArray,
+    "float3[]"
+);
+
+array_type!(
+    Vec4Array,
+    Vec4ArrayRef,
+    Vec4ArrayIterator,
+    Vec4,
+    gf_Vec4fArray_t,
+    gf_Vec4fArray_default,
+    gf_Vec4fArray_dtor,
+    gf_Vec4fArray_copy,
+    gf_Vec4fArray_size,
+    gf_Vec4fArray_cdata,
+    gf_Vec4fArray_data,
+    gf_Vec4fArray_push_back,
+    gf_Vec4fArray_resize,
+    as_vec4_array,
+    vt_Value_IsHolding_VtVec4fArray,
+    vt_Value_Get_VtVec4fArray,
+    vt_Value_from_VtVec4fArray,
+    vt_Value_CanCast_VtVec4fArray,
+    vt_Value_Cast_VtVec4fArray,
+    "float4[]"
+);
+
+array_type!(
+    Vec2dArray,
+    Vec2dArrayRef,
+    Vec2dArrayIterator,
+    DVec2,
+    gf_Vec2dArray_t,
+    gf_Vec2dArray_default,
+    gf_Vec2dArray_dtor,
+    gf_Vec2dArray_copy,
+    gf_Vec2dArray_size,
+    gf_Vec2dArray_cdata,
+    gf_Vec2dArray_data,
+    gf_Vec2dArray_push_back,
+    gf_Vec2dArray_resize,
+    as_vec2d_array,
+    vt_Value_IsHolding_VtVec2dArray,
+    vt_Value_Get_VtVec2dArray,
+    vt_Value_from_VtVec2dArray,
+    vt_Value_CanCast_VtVec2dArray,
+    vt_Value_Cast_VtVec2dArray,
+    "double2[]"
+);
+
+array_type!(
+    Vec3dArray,
+    Vec3dArrayRef,
+    Vec3dArrayIterator,
+    DVec3,
+    gf_Vec3dArray_t,
+    gf_Vec3dArray_default,
+    gf_Vec3dArray_dtor,
+    gf_Vec3dArray_copy,
+    gf_Vec3dArray_size,
+    gf_Vec3dArray_cdata,
+    gf_Vec3dArray_data,
+    gf_Vec3dArray_push_back,
+    gf_Vec3dArray_resize,
+    as_vec3d_array,
+    vt_Value_IsHolding_VtVec3dArray,
+    vt_Value_Get_VtVec3dArray,
+    vt_Value_from_VtVec3dArray,
+    vt_Value_CanCast_VtVec3dArray,
+    vt_Value_Cast_VtVec3dArray,
+    "double3[]"
+);
+
+array_type!(
+    Vec4dArray,
+    Vec4dArrayRef,
+    Vec4dArrayIterator,
+    DVec4,
+    gf_Vec4dArray_t,
+    gf_Vec4dArray_default,
+    gf_Vec4dArray_dtor,
+    gf_Vec4dArray_copy,
+    gf_Vec4dArray_size,
+    gf_Vec4dArray_cdata,
+    gf_Vec4dArray_data,
+    gf_Vec4dArray_push_back,
+    gf_Vec4dArray_resize,
+    as_vec4d_array,
+    vt_Value_IsHolding_VtVec4dArray,
+    vt_Value_Get_VtVec4dArray,
+    vt_Value_from_VtVec4dArray,
+    vt_Value_CanCast_VtVec4dArray,
+    vt_Value_Cast_VtVec4dArray,
+    "double4[]"
+);
+
+array_type!(
+    Vec2hArray,
+    Vec2hArrayRef,
+    Vec2hArrayIterator,
+    gf::Vec2h,
+    gf_Vec2hArray_t,
+    gf_Vec2hArray_default,
+    gf_Vec2hArray_dtor,
+    gf_Vec2hArray_copy,
+    gf_Vec2hArray_size,
+    gf_Vec2hArray_cdata,
+    gf_Vec2hArray_data,
+    gf_Vec2hArray_push_back,
+    gf_Vec2hArray_resize,
+    as_vec2h_array,
+    vt_Value_IsHolding_VtVec2hArray,
+    vt_Value_Get_VtVec2hArray,
+    vt_Value_from_VtVec2hArray,
+    vt_Value_CanCast_VtVec2hArray,
+    vt_Value_Cast_VtVec2hArray,
+    "half2[]"
+);
+
+array_type!(
+    Vec3hArray,
+    Vec3hArrayRef,
+    Vec3hArrayIterator,
+    gf::Vec3h,
+    gf_Vec3hArray_t,
+    gf_Vec3hArray_default,
+    gf_Vec3hArray_dtor,
+    gf_Vec3hArray_copy,
+    gf_Vec3hArray_size,
+    gf_Vec3hArray_cdata,
+    gf_Vec3hArray_data,
+    gf_Vec3hArray_push_back,
+    gf_Vec3hArray_resize,
+    as_vec3h_array,
+    vt_Value_IsHolding_VtVec3hArray,
+    vt_Value_Get_VtVec3hArray,
+    vt_Value_from_VtVec3hArray,
+    vt_Value_CanCast_VtVec3hArray,
+    vt_Value_Cast_VtVec3hArray,
+    "half3[]"
+);
+
+array_type!(
+    Vec4hArray,
+    Vec4hArrayRef,
+    Vec4hArrayIterator,
+    gf::Vec4h,
+    gf_Vec4hArray_t,
+    gf_Vec4hArray_default,
+    gf_Vec4hArray_dtor,
+    gf_Vec4hArray_copy,
+    gf_Vec4hArray_size,
+    gf_Vec4hArray_cdata,
+    gf_Vec4hArray_data,
+    gf_Vec4hArray_push_back,
+    gf_Vec4hArray_resize,
+    as_vec4h_array,
+    vt_Value_IsHolding_VtVec4hArray,
+    vt_Value_Get_VtVec4hArray,
+    vt_Value_from_VtVec4hArray,
+    vt_Value_CanCast_VtVec4hArray,
+    vt_Value_Cast_VtVec4hArray,
+    "half4[]"
+);
+
+array_type!(
+    Vec2iArray,
+    Vec2iArrayRef,
+    Vec2iArrayIterator,
+    IVec2,
+    gf_Vec2iArray_t,
+    gf_Vec2iArray_default,
+    gf_Vec2iArray_dtor,
+    gf_Vec2iArray_copy,
+    gf_Vec2iArray_size,
+    gf_Vec2iArray_cdata,
+    gf_Vec2iArray_data,
+    gf_Vec2iArray_push_back,
+    gf_Vec2iArray_resize,
+    as_vec2i_array,
+    vt_Value_IsHolding_VtVec2iArray,
+    vt_Value_Get_VtVec2iArray,
+    vt_Value_from_VtVec2iArray,
+    vt_Value_CanCast_VtVec2iArray,
+    vt_Value_Cast_VtVec2iArray,
+    "int2[]"
+);
+
+array_type!(
+    Vec3iArray,
+    Vec3iArrayRef,
+    Vec3iArrayIterator,
+    IVec3,
+    gf_Vec3iArray_t,
+    gf_Vec3iArray_default,
+    gf_Vec3iArray_dtor,
+    gf_Vec3iArray_copy,
+    gf_Vec3iArray_size,
+    gf_Vec3iArray_cdata,
+    gf_Vec3iArray_data,
+    gf_Vec3iArray_push_back,
+    gf_Vec3iArray_resize,
+    as_vec3i_array,
+    vt_Value_IsHolding_VtVec3iArray,
+    vt_Value_Get_VtVec3iArray,
+    vt_Value_from_VtVec3iArray,
+    vt_Value_CanCast_VtVec3iArray,
+    vt_Value_Cast_VtVec3iArray,
+    "int3[]"
+);
+
+array_type!(
+    Vec4iArray,
+    Vec4iArrayRef,
+    Vec4iArrayIterator,
+    IVec4,
+    gf_Vec4iArray_t,
+    gf_Vec4iArray_default,
+    gf_Vec4iArray_dtor,
+    gf_Vec4iArray_copy,
+    gf_Vec4iArray_size,
+    gf_Vec4iArray_cdata,
+    gf_Vec4iArray_data,
+    gf_Vec4iArray_push_back,
+    gf_Vec4iArray_resize,
+    as_vec4i_array,
+    vt_Value_IsHolding_VtVec4iArray,
+    vt_Value_Get_VtVec4iArray,
+    vt_Value_from_VtVec4iArray,
+    vt_Value_CanCast_VtVec4iArray,
+    vt_Value_Cast_VtVec4iArray,
+    "int4[]"
+);
+
+array_type!(
+    QuatfArray,
+    QuatfArrayRef,
+    QuatfArrayIterator,
+    Quat,
+    gf_QuatfArray_t,
+    gf_QuatfArray_default,
+    gf_QuatfArray_dtor,
+    gf_QuatfArray_copy,
+    gf_QuatfArray_size,
+    gf_QuatfArray_cdata,
+    gf_QuatfArray_data,
+    gf_QuatfArray_push_back,
+    gf_QuatfArray_resize,
+    as_quatf_array,
+    vt_Value_IsHolding_VtQuatfArray,
+    vt_Value_Get_VtQuatfArray,
+    vt_Value_from_VtQuatfArray,
+    vt_Value_CanCast_VtQuatfArray,
+    vt_Value_Cast_VtQuatfArray,
+    "quatf[]"
+);
+
+array_type!(
+    QuatdArray,
+    QuatdArrayRef,
+    QuatdArrayIterator,
+    DQuat,
+    gf_QuatdArray_t,
+    gf_QuatdArray_default,
+    gf_QuatdArray_dtor,
+    gf_QuatdArray_copy,
+    gf_QuatdArray_size,
+    gf_QuatdArray_cdata,
+    gf_QuatdArray_data,
+    gf_QuatdArray_push_back,
+    gf_QuatdArray_resize,
+    as_quatd_array,
+    vt_Value_IsHolding_VtQuatdArray,
+    vt_Value_Get_VtQuatdArray,
+    vt_Value_from_VtQuatdArray,
+    vt_Value_CanCast_VtQuatdArray,
+    vt_Value_Cast_VtQuatdArray,
+    "quatd[]"
+);
+
+array_type!(
+    QuathArray,
+    QuathArrayRef,
+    QuathArrayIterator,
+    gf::Quath,
+    gf_QuathArray_t,
+    gf_QuathArray_default,
+    gf_QuathArray_dtor,
+    gf_QuathArray_copy,
+    gf_QuathArray_size,
+    gf_QuathArray_cdata,
+    gf_QuathArray_data,
+    gf_QuathArray_push_back,
+    gf_QuathArray_resize,
+    as_quath_array,
+    vt_Value_IsHolding_VtQuathArray,
+    vt_Value_Get_VtQuathArray,
+    vt_Value_from_VtQuathArray,
+    vt_Value_CanCast_VtQuathArray,
+    vt_Value_Cast_VtQuathArray,
+    "quath[]"
+);
+
+array_type!(
+    Matrix2dArray,
+    Matrix2dArrayRef,
+    Matrix2dArrayIterator,
+    DMat2,
+    gf_Matrix2dArray_t,
+    gf_Matrix2dArray_default,
+    gf_Matrix2dArray_dtor,
+    gf_Matrix2dArray_copy,
+    gf_Matrix2dArray_size,
+    gf_Matrix2dArray_cdata,
+    gf_Matrix2dArray_data,
+    gf_Matrix2dArray_push_back,
+    gf_Matrix2dArray_resize,
+    as_matrix2d_array,
+    vt_Value_IsHolding_VtMatrix2dArray,
+    vt_Value_Get_VtMatrix2dArray,
+    vt_Value_from_VtMatrix2dArray,
+    vt_Value_CanCast_VtMatrix2dArray,
+    vt_Value_Cast_VtMatrix2dArray,
+    "matrix2d[]"
+);
+
+array_type!(
+    Matrix3dArray,
+    Matrix3dArrayRef,
+    Matrix3dArrayIterator,
+    DMat3,
+    gf_Matrix3dArray_t,
+    gf_Matrix3dArray_default,
+    gf_Matrix3dArray_dtor,
+    gf_Matrix3dArray_copy,
+    gf_Matrix3dArray_size,
+    gf_Matrix3dArray_cdata,
+    gf_Matrix3dArray_data,
+    gf_Matrix3dArray_push_back,
+    gf_Matrix3dArray_resize,
+    as_matrix3d_array,
+    vt_Value_IsHolding_VtMatrix3dArray,
+    vt_Value_Get_VtMatrix3dArray,
+    vt_Value_from_VtMatrix3dArray,
+    vt_Value_CanCast_VtMatrix3dArray,
+    vt_Value_Cast_VtMatrix3dArray,
+    "matrix3d[]"
+);
+
+array_type!(
+    Matrix4dArray,
+    Matrix4dArrayRef,
+    Matrix4dArrayIterator,
+    DMat4,
+    gf_Matrix4dArray_t,
+    gf_Matrix4dArray_default,
+    gf_Matrix4dArray_dtor,
+    gf_Matrix4dArray_copy,
+    gf_Matrix4dArray_size,
+    gf_Matrix4dArray_cdata,
+    gf_Matrix4dArray_data,
+    gf_Matrix4dArray_push_back,
+    gf_Matrix4dArray_resize,
+    as_matrix4d_array,
+    vt_Value_IsHolding_VtMatrix4dArray,
+    vt_Value_Get_VtMatrix4dArray,
+    vt_Value_from_VtMatrix4dArray,
+    vt_Value_CanCast_VtMatrix4dArray,
+    vt_Value_Cast_VtMatrix4dArray,
+    "matrix4d[]"
+);
+
+array_type!(
+    TimeCodeArray,
+    TimeCodeArrayRef,
+    TimeCodeArrayIterator,
+    sdf::TimeCode,
+    sdf_TimeCodeArray_t,
+    sdf_TimeCodeArray_default,
+    sdf_TimeCodeArray_dtor,
+    sdf_TimeCodeArray_copy,
+    sdf_TimeCodeArray_size,
+    sdf_TimeCodeArray_cdata,
+    sdf_TimeCodeArray_data,
+    sdf_TimeCodeArray_push_back,
+    sdf_TimeCodeArray_resize,
+    as_time_code_array,
+    vt_Value_IsHolding_SdfTimeCodeArray,
+    vt_Value_Get_SdfTimeCodeArray,
+    vt_Value_from_SdfTimeCodeArray,
+    vt_Value_CanCast_SdfTimeCodeArray,
+    vt_Value_Cast_SdfTimeCodeArray,
+    "timecode[]"
+);
+
+pub struct Dictionary {
+    pub(crate) ptr: *mut ffi::vt_Dictionary_t,
 }
 
-impl Vec3Array {
-    pub fn size(&self) -> usize {
+impl Dictionary {
+    pub fn new() -> Self {
+        unsafe {
+            let mut ptr = std::ptr::null_mut();
+            ffi::vt_Dictionary_default(&mut ptr);
+            Dictionary { ptr }
+        }
+    }
+
+    pub fn len(&self) -> usize {
         unsafe {
             let mut result = 0;
-            ffi::gf_Vec3fArray_size(self.ptr, &mut result);
+            ffi::vt_Dictionary_size(self.ptr, &mut result);
             result
         }
     }
 
-    pub fn at(&self, index: usize) -> &Vec3 {
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    pub fn contains_key(&self, key: &str) -> bool {
+        self.get(key).is_some()
+    }
+
+    pub fn get(&self, key: &str) -> Option<ValueRef<'_>> {
+        // no key can contain a nul byte
+        let key = CString::new(key).ok()?;
         unsafe {
             let mut ptr = std::ptr::null_mut();
-            ffi::gf_Vec3fArray_op_index(self.ptr, index, &mut ptr);
-            &*(ptr as *mut Vec3)
+            ffi::vt_Dictionary_get(self.ptr, key.as_ptr() as *mut i8, &mut ptr);
+            if ptr.is_null() {
+                None
+            } else {
+                Some(ValueRef::_from_ptr(ptr))
+            }
         }
     }
 
-    pub fn iter(&self) -> Vec3ArrayIterator {
-        Vec3ArrayIterator {
-            vec: self,
-            current: 0,
-            end: self.size(),
+    /// Look up a value in nested dictionaries, where `key_path` is a list of
+    /// keys separated by `:`, e.g. `"a:b:c"`.
+    pub fn get_at_path(&self, key_path: &str) -> Option<ValueRef<'_>> {
+        let key_path = CString::new(key_path).ok()?;
+        unsafe {
+            let mut ptr = std::ptr::null_mut();
+            ffi::vt_Dictionary_GetValueAtPath(self.ptr, key_path.as_ptr() as *mut i8, &mut ptr);
+            if ptr.is_null() {
+                None
+            } else {
+                Some(ValueRef::_from_ptr(ptr))
+            }
         }
     }
-}
 
-impl<'a> IntoIterator for &'a Vec3Array {
-    type Item = &'a Vec3;
-    type IntoIter = Vec3ArrayIterator<'a>;
-
-    fn into_iter(self) -> Self::IntoIter {
-        self.iter()
+    /// Panics if `key` contains an interior nul byte.
+    pub fn insert<T: ToValue + ?Sized>(&mut self, key: &str, value: &T) {
+        let key = CString::new(key).unwrap();
+        let value = value.to_value();
+        unsafe {
+            ffi::vt_Dictionary_insert(self.ptr, key.as_ptr() as *mut i8, value.ptr);
+        }
     }
-}
 
-pub struct Vec3ArrayIterator<'a> {
-    vec: &'a Vec3Array,
-    current: usize,
-    end: usize,
-}
+    /// Set a value in nested dictionaries, creating any intermediate
+    /// dictionaries named in `key_path` that do not exist yet. Panics if
+    /// `key_path` contains an interior nul byte.
+    pub fn insert_at_path<T: ToValue + ?Sized>(&mut self, key_path: &str, value: &T) {
+        let key_path = CString::new(key_path).unwrap();
+        let value = value.to_value();
+        unsafe {
+            ffi::vt_Dictionary_SetValueAtPath(self.ptr, key_path.as_ptr() as *mut i8, value.ptr);
+        }
+    }
 
-impl<'a> Iterator for Vec3ArrayIterator<'a> {
-    type Item = &'a Vec3;
+    pub fn remove(&mut self, key: &str) -> bool {
+        let key = match CString::new(key) {
+            Ok(key) => key,
+            Err(_) => return false,
+        };
+        unsafe {
+            let mut count = 0;
+            ffi::vt_Dictionary_erase(self.ptr, key.as_ptr() as *mut i8, &mut count);
+            count != 0
+        }
+    }
 
-    fn next(&mut self) -> Option<Self::Item> {
-        if self.current == self.end {
-            None
-        } else {
-            let cur = self.current;
-            self.current += 1;
-            Some(self.vec.at(cur))
+    pub fn remove_at_path(&mut self, key_path: &str) {
+        let key_path = match CString::new(key_path) {
+            Ok(key_path) => key_path,
+            Err(_) => return,
+        };
+        unsafe {
+            ffi::vt_Dictionary_EraseValueAtPath(self.ptr, key_path.as_ptr() as *mut i8);
         }
     }
-}
 
-pub struct Vec3ArrayRef {
-    pub(crate) ptr: *mut ffi::gf_Vec3fArray_t,
-}
+    pub fn keys(&self) -> impl Iterator<Item = &str> + '_ {
+        self.iter().map(|(key, _)| key)
+    }
 
-impl std::ops::Deref for Vec3ArrayRef {
-    type Target = Vec3Array;
+    pub fn iter(&self) -> DictionaryIterator {
+        unsafe {
+            let mut current = std::ptr::null_mut();
+            ffi::vt_Dictionary_begin(self.ptr, &mut current);
 
-    fn deref(&self) -> &Self::Target {
-        unsafe { &*(self as *const Vec3ArrayRef as *const Vec3Array) }
+            let mut end = std::ptr::null_mut();
+            ffi::vt_Dictionary_end(self.ptr, &mut end);
+
+            DictionaryIterator {
+                _dict: self,
+                current,
+                end,
+            }
+        }
     }
 }
 
-pub struct Vec4Array {
-    pub(crate) ptr: *mut ffi::gf_Vec4fArray_t,
+impl Default for Dictionary {
+    fn default() -> Self {
+        Dictionary::new()
+    }
 }
 
-impl Vec4Array {
-    pub fn size(&self) -> usize {
+impl Clone for Dictionary {
+    fn clone(&self) -> Self {
         unsafe {
-            let mut result = 0;
-            ffi::gf_Vec4fArray_size(self.ptr, &mut result);
-            result
+            let mut ptr = std::ptr::null_mut();
+            ffi::vt_Dictionary_copy(self.ptr, &mut ptr);
+            Dictionary { ptr }
         }
     }
+}
 
-    pub fn at(&self, index: usize) -> &Vec4 {
+impl Drop for Dictionary {
+    fn drop(&mut self) {
         unsafe {
-            let mut ptr = std::ptr::null_mut();
-            ffi::gf_Vec4fArray_op_index(self.ptr, index, &mut ptr);
-            &*(ptr as *mut Vec4)
+            ffi::vt_Dictionary_dtor(self.ptr);
         }
     }
+}
 
-    pub fn iter(&self) -> Vec4ArrayIterator {
-        Vec4ArrayIterator {
-            vec: self,
-            current: 0,
-            end: self.size(),
+impl ToValue for Dictionary {
+    fn to_value(&self) -> Value {
+        unsafe {
+            let mut ptr = std::ptr::null_mut();
+            ffi::vt_Value_from_VtDictionary(self.ptr, &mut ptr);
+            Value { ptr }
         }
     }
 }
 
-impl<'a> IntoIterator for &'a Vec4Array {
-    type Item = &'a Vec4;
-    type IntoIter = Vec4ArrayIterator<'a>;
+impl<'a> IntoIterator for &'a Dictionary {
+    type Item = (&'a str, ValueRef<'a>);
+    type IntoIter = DictionaryIterator<'a>;
 
     fn into_iter(self) -> Self::IntoIter {
         self.iter()
     }
 }
 
-pub struct Vec4ArrayIterator<'a> {
-    vec: &'a Vec4Array,
-    current: usize,
-    end: usize,
+pub struct DictionaryIterator<'a> {
+    _dict: &'a Dictionary,
+    current: *mut ffi::vt_DictionaryIterator_t,
+    end: *mut ffi::vt_DictionaryIterator_t,
 }
 
-impl<'a> Iterator for Vec4ArrayIterator<'a> {
-    type Item = &'a Vec4;
+impl<'a> Iterator for DictionaryIterator<'a> {
+    type Item = (&'a str, ValueRef<'a>);
 
     fn next(&mut self) -> Option<Self::Item> {
-        if self.current == self.end {
-            None
-        } else {
-            let cur = self.current;
-            self.current += 1;
-            Some(self.vec.at(cur))
+        unsafe {
+            let mut at_end = false;
+            ffi::vt_DictionaryIterator_op_eq(self.current, self.end, &mut at_end);
+            if at_end {
+                return None;
+            }
+
+            let mut key = std::ptr::null_mut();
+            ffi::vt_DictionaryIterator_key(self.current, &mut key);
+            let mut c_key = std::ptr::null_mut();
+            ffi::std_String_c_str(key, &mut c_key);
+            let key = CStr::from_ptr(c_key).to_str().unwrap();
+
+            let mut ptr = std::ptr::null_mut();
+            ffi::vt_DictionaryIterator_value(self.current, &mut ptr);
+
+            let mut dummy = std::ptr::null_mut();
+            ffi::vt_DictionaryIterator_op_inc(self.current, &mut dummy);
+
+            Some((key, ValueRef::_from_ptr(ptr)))
+        }
+    }
+}
+
+impl<'a> Drop for DictionaryIterator<'a> {
+    fn drop(&mut self) {
+        unsafe {
+            ffi::vt_DictionaryIterator_dtor(self.current);
+            ffi::vt_DictionaryIterator_dtor(self.end);
         }
     }
 }
 
-pub struct Vec4ArrayRef {
-    pub(crate) ptr: *mut ffi::gf_Vec4fArray_t,
+pub struct DictionaryRef {
+    pub(crate) ptr: *mut ffi::vt_Dictionary_t,
 }
 
-impl std::ops::Deref for Vec4ArrayRef {
-    type Target = Vec4Array;
+impl std::ops::Deref for DictionaryRef {
+    type Target = Dictionary;
 
     fn deref(&self) -> &Self::Target {
-        unsafe { &*(self as *const Vec4ArrayRef as *const Vec4Array) }
+        unsafe { &*(self as *const DictionaryRef as *const Dictionary) }
     }
 }
 
+#[repr(transparent)]
 pub struct Value {
     pub(crate) ptr: *mut ffi::vt_Value_t,
 }
@@ -511,14 +1638,14 @@ impl Value {
 }
 
 impl Value {
-    pub fn as_token(&self) -> Option<tf::TokenRef> {
+    pub fn as_token(&self) -> Option<tf::TokenRef<'_>> {
         unsafe {
             let mut is_holding = false;
             ffi::vt_Value_IsHolding_TfToken(self.ptr, &mut is_holding);
             if is_holding {
                 let mut ptr = std::ptr::null_mut();
                 ffi::vt_Value_Get_TfToken(self.ptr, &mut ptr);
-                Some(tf::TokenRef { ptr })
+                Some(tf::TokenRef::_from_ptr(ptr))
             } else {
                 None
             }
@@ -530,356 +1657,844 @@ impl Value {
             let mut is_holding = false;
             ffi::vt_Value_IsHolding_VtTokenArray(self.ptr, &mut is_holding);
             if is_holding {
-                let mut ptr = std::ptr::null_mut();
-                ffi::vt_Value_Get_VtTokenArray(self.ptr, &mut ptr);
-                Some(TokenArrayRef { ptr })
+                Some(TokenArrayRef::_from_value(self))
             } else {
                 None
             }
         }
     }
 
-    pub fn as_int_array(&self) -> Option<IntArrayRef> {
+    pub fn as_string(&self) -> Option<cpp::StringRef<'_>> {
         unsafe {
             let mut is_holding = false;
-            ffi::vt_Value_IsHolding_VtIntArray(self.ptr, &mut is_holding);
+            ffi::vt_Value_IsHolding_string(self.ptr, &mut is_holding);
             if is_holding {
                 let mut ptr = std::ptr::null_mut();
-                ffi::vt_Value_Get_VtIntArray(self.ptr, &mut ptr);
-                Some(IntArrayRef { ptr })
+                ffi::vt_Value_Get_string(self.ptr, &mut ptr);
+                Some(cpp::StringRef::_from_ptr(ptr))
             } else {
                 None
             }
         }
     }
 
-
-    pub fn as_float_array(&self) -> Option<FloatArrayRef> {
+    pub fn as_string_array(&self) -> Option<StringArrayRef> {
         unsafe {
             let mut is_holding = false;
-            ffi::vt_Value_IsHolding_VtFloatArray(self.ptr, &mut is_holding);
+            ffi::vt_Value_IsHolding_VtStringArray(self.ptr, &mut is_holding);
             if is_holding {
-                let mut ptr = std::ptr::null_mut();
-                ffi::vt_Value_Get_VtFloatArray(self.ptr, &mut ptr);
-                Some(FloatArrayRef { ptr })
+                Some(StringArrayRef::_from_value(self))
             } else {
                 None
             }
         }
     }
 
-    pub fn as_double_array(&self) -> Option<DoubleArrayRef> {
+    pub fn as_asset_path_array(&self) -> Option<AssetPathArrayRef> {
         unsafe {
             let mut is_holding = false;
-            ffi::vt_Value_IsHolding_VtDoubleArray(self.ptr, &mut is_holding);
+            ffi::vt_Value_IsHolding_SdfAssetPathArray(self.ptr, &mut is_holding);
             if is_holding {
-                let mut ptr = std::ptr::null_mut();
-                ffi::vt_Value_Get_VtDoubleArray(self.ptr, &mut ptr);
-                Some(DoubleArrayRef { ptr })
+                Some(AssetPathArrayRef::_from_value(self))
             } else {
                 None
             }
         }
     }
 
-    pub fn as_vec2_array(&self) -> Option<Vec2ArrayRef> {
+    pub fn as_dictionary(&self) -> Option<DictionaryRef> {
         unsafe {
             let mut is_holding = false;
-            ffi::vt_Value_IsHolding_VtVec2fArray(self.ptr, &mut is_holding);
+            ffi::vt_Value_IsHolding_VtDictionary(self.ptr, &mut is_holding);
             if is_holding {
                 let mut ptr = std::ptr::null_mut();
-                ffi::vt_Value_Get_VtVec2fArray(self.ptr, &mut ptr);
-                Some(Vec2ArrayRef { ptr })
+                ffi::vt_Value_Get_VtDictionary(self.ptr, &mut ptr);
+                Some(DictionaryRef { ptr })
             } else {
                 None
             }
         }
     }
 
-    pub fn as_vec3_array(&self) -> Option<Vec3ArrayRef> {
+    pub fn as_asset_path(&self) -> Option<sdf::AssetPathRef<'_>> {
         unsafe {
             let mut is_holding = false;
-            ffi::vt_Value_IsHolding_VtVec3fArray(self.ptr, &mut is_holding);
+            ffi::vt_Value_IsHolding_SdfAssetPath(self.ptr, &mut is_holding);
             if is_holding {
                 let mut ptr = std::ptr::null_mut();
-                ffi::vt_Value_Get_VtVec3fArray(self.ptr, &mut ptr);
-                Some(Vec3ArrayRef { ptr })
+                ffi::vt_Value_Get_SdfAssetPath(self.ptr, &mut ptr);
+                Some(sdf::AssetPathRef::_from_ptr(ptr))
             } else {
                 None
             }
         }
     }
+}
+
+impl Value {
+    /// Look up the held type once and return a borrowed view of the contents.
+    pub fn kind(&self) -> ValueKind {
+        let type_name = match sdf::ValueTypeName::for_value(self) {
+            Some(type_name) => type_name.as_token().text(),
+            None => {
+                // dictionaries are not registered as sdf value types
+                return if self.is_empty() {
+                    ValueKind::Empty
+                } else if let Some(dict) = self.as_dictionary() {
+                    ValueKind::Dictionary(dict)
+                } else {
+                    ValueKind::Other
+                };
+            }
+        };
 
-    pub fn as_vec4_array(&self) -> Option<Vec4ArrayRef> {
         unsafe {
-            let mut is_holding = false;
-            ffi::vt_Value_IsHolding_VtVec4fArray(self.ptr, &mut is_holding);
-            if is_holding {
-                let mut ptr = std::ptr::null_mut();
-                ffi::vt_Value_Get_VtVec4fArray(self.ptr, &mut ptr);
-                Some(Vec4ArrayRef { ptr })
-            } else {
-                None
+            match type_name {
+                "bool" => ValueKind::Bool(self.get_unchecked()),
+                "int" => ValueKind::Int(self.get_unchecked()),
+                "int64" => ValueKind::Int64(self.get_unchecked()),
+                "uint" => ValueKind::UInt(self.get_unchecked()),
+                "uchar" => ValueKind::UChar(self.get_unchecked()),
+                "half" => ValueKind::Half(self.get_unchecked()),
+                "float" => ValueKind::Float(self.get_unchecked()),
+                "double" => ValueKind::Double(self.get_unchecked()),
+                "float2" => ValueKind::Vec2(self.get_unchecked()),
+                "float3" => ValueKind::Vec3(self.get_unchecked()),
+                "float4" => ValueKind::Vec4(self.get_unchecked()),
+                "double2" => ValueKind::Vec2d(self.get_unchecked()),
+                "double3" => ValueKind::Vec3d(self.get_unchecked()),
+                "double4" => ValueKind::Vec4d(self.get_unchecked()),
+                "half2" => ValueKind::Vec2h(self.get_unchecked()),
+                "half3" => ValueKind::Vec3h(self.get_unchecked()),
+                "half4" => ValueKind::Vec4h(self.get_unchecked()),
+                "int2" => ValueKind::Vec2i(self.get_unchecked()),
+                "int3" => ValueKind::Vec3i(self.get_unchecked()),
+                "int4" => ValueKind::Vec4i(self.get_unchecked()),
+                "quatf" => ValueKind::Quatf(self.get_unchecked()),
+                "quatd" => ValueKind::Quatd(self.get_unchecked()),
+                "quath" => ValueKind::Quath(self.get_unchecked()),
+                "matrix2d" => ValueKind::Matrix2d(self.get_unchecked()),
+                "matrix3d" => ValueKind::Matrix3d(self.get_unchecked()),
+                "matrix4d" => ValueKind::Matrix4d(self.get_unchecked()),
+                "timecode" => ValueKind::TimeCode(self.get_unchecked()),
+                "string" => {
+                    let mut ptr = std::ptr::null_mut();
+                    ffi::vt_Value_Get_string(self.ptr, &mut ptr);
+                    ValueKind::String(cpp::StringRef::_from_ptr(ptr))
+                }
+                "token" => {
+                    let mut ptr = std::ptr::null_mut();
+                    ffi::vt_Value_Get_TfToken(self.ptr, &mut ptr);
+                    ValueKind::Token(tf::TokenRef::_from_ptr(ptr))
+                }
+                "asset" => {
+                    let mut ptr = std::ptr::null_mut();
+                    ffi::vt_Value_Get_SdfAssetPath(self.ptr, &mut ptr);
+                    ValueKind::AssetPath(sdf::AssetPathRef::_from_ptr(ptr))
+                }
+                "bool[]" => ValueKind::BoolArray(BoolArrayRef::_from_value(self)),
+                "int[]" => ValueKind::IntArray(IntArrayRef::_from_value(self)),
+                "int64[]" => ValueKind::Int64Array(Int64ArrayRef::_from_value(self)),
+                "uint[]" => ValueKind::UIntArray(UIntArrayRef::_from_value(self)),
+                "uchar[]" => ValueKind::UCharArray(UCharArrayRef::_from_value(self)),
+                "half[]" => ValueKind::HalfArray(HalfArrayRef::_from_value(self)),
+                "float[]" => ValueKind::FloatArray(FloatArrayRef::_from_value(self)),
+                "double[]" => ValueKind::DoubleArray(DoubleArrayRef::_from_value(self)),
+                "float2[]" => ValueKind::Vec2Array(Vec2ArrayRef::_from_value(self)),
+                "float3[]" => ValueKind::Vec3Array(Vec3ArrayRef::_from_value(self)),
+                "float4[]" => ValueKind::Vec4Array(Vec4ArrayRef::_from_value(self)),
+                "double2[]" => ValueKind::Vec2dArray(Vec2dArrayRef::_from_value(self)),
+                "double3[]" => ValueKind::Vec3dArray(Vec3dArrayRef::_from_value(self)),
+                "double4[]" => ValueKind::Vec4dArray(Vec4dArrayRef::_from_value(self)),
+                "half2[]" => ValueKind::Vec2hArray(Vec2hArrayRef::_from_value(self)),
+                "half3[]" => ValueKind::Vec3hArray(Vec3hArrayRef::_from_value(self)),
+                "half4[]" => ValueKind::Vec4hArray(Vec4hArrayRef::_from_value(self)),
+                "int2[]" => ValueKind::Vec2iArray(Vec2iArrayRef::_from_value(self)),
+                "int3[]" => ValueKind::Vec3iArray(Vec3iArrayRef::_from_value(self)),
+                "int4[]" => ValueKind::Vec4iArray(Vec4iArrayRef::_from_value(self)),
+                "quatf[]" => ValueKind::QuatfArray(QuatfArrayRef::_from_value(self)),
+                "quatd[]" => ValueKind::QuatdArray(QuatdArrayRef::_from_value(self)),
+                "quath[]" => ValueKind::QuathArray(QuathArrayRef::_from_value(self)),
+                "matrix2d[]" => ValueKind::Matrix2dArray(Matrix2dArrayRef::_from_value(self)),
+                "matrix3d[]" => ValueKind::Matrix3dArray(Matrix3dArrayRef::_from_value(self)),
+                "matrix4d[]" => ValueKind::Matrix4dArray(Matrix4dArrayRef::_from_value(self)),
+                "timecode[]" => ValueKind::TimeCodeArray(TimeCodeArrayRef::_from_value(self)),
+                "token[]" => ValueKind::TokenArray(TokenArrayRef::_from_value(self)),
+                "string[]" => ValueKind::StringArray(StringArrayRef::_from_value(self)),
+                "asset[]" => ValueKind::AssetPathArray(AssetPathArrayRef::_from_value(self)),
+                _ => ValueKind::Other,
             }
         }
     }
 
-    pub fn as_asset_path(&self) -> Option<sdf::AssetPathRef> {
+    pub fn is_empty(&self) -> bool {
         unsafe {
-            let mut is_holding = false;
-            ffi::vt_Value_IsHolding_SdfAssetPath(self.ptr, &mut is_holding);
-            if is_holding {
-                let mut ptr = std::ptr::null_mut();
-                ffi::vt_Value_Get_SdfAssetPath(self.ptr, &mut ptr);
-                Some(sdf::AssetPathRef { ptr })
-            } else {
-                None
-            }
+            let mut result = false;
+            ffi::vt_Value_IsEmpty(self.ptr, &mut result);
+            result
         }
     }
-}
-
-pub struct ValueRef {
-    pub(crate) ptr: *mut ffi::vt_Value_t,
-}
-
-impl std::ops::Deref for ValueRef {
-    type Target = Value;
 
-    fn deref(&self) -> &Self::Target {
-        unsafe { &*(self as *const ValueRef as *const Value) }
+    pub fn can_cast<T: ValueCast>(&self) -> bool {
+        T::can_cast(self)
     }
-}
 
-impl Drop for Value {
-    fn drop(&mut self) {
-        unsafe {
-            ffi::vt_Value_dtor(self.ptr);
-        }
+    /// Convert the held value to `T` using USD's registered casts, e.g.
+    /// `double` to `float` or `int[]` to `int64[]`. Cast errors name both
+    /// types by their Sdf value type names.
+    pub fn cast<T: ValueCast>(&self) -> Result<T, Error> {
+        T::cast(self).ok_or_else(|| Error::Cast {
+            from: match sdf::ValueTypeName::for_value(self) {
+                Some(type_name) => type_name.as_token().text().to_string(),
+                None => self.type_name(),
+            },
+            to: T::TYPE_NAME,
+        })
     }
-}
 
-pub trait ValueMember {
-    fn get(value: &Value) -> Option<&Self>;
-    fn is_holding(value: &Value) -> bool;
-    fn from(member: &Self) -> Value;
-}
-
-impl ValueMember for i32 {
-    fn get(value: &Value) -> Option<&Self> {
-        if Self::is_holding(value) {
-            unsafe {
-                let mut ptr = std::ptr::null_mut();
-                ffi::vt_Value_Get_int(value.ptr, &mut ptr);
-                Some(&*ptr)
-            }
-        } else {
-            None
+    pub fn type_name(&self) -> String {
+        unsafe {
+            let mut ptr = std::ptr::null_mut();
+            ffi::vt_Value_GetTypeName(self.ptr, &mut ptr);
+            let type_name = cpp::String { ptr };
+            type_name.as_str().to_string()
         }
     }
 
-    fn is_holding(value: &Value) -> bool {
+    pub fn is_array(&self) -> bool {
         unsafe {
             let mut result = false;
-            ffi::vt_Value_IsHolding_int(value.ptr, &mut result);
+            ffi::vt_Value_IsArrayValued(self.ptr, &mut result);
             result
         }
     }
 
-    fn from(member: &Self) -> Value {
+    pub fn array_size(&self) -> usize {
         unsafe {
-            let mut ptr = std::ptr::null_mut();
-            ffi::vt_Value_from_int(*member, &mut ptr);
-            Value { ptr }
+            let mut result = 0;
+            ffi::vt_Value_GetArraySize(self.ptr, &mut result);
+            result
         }
     }
+
+    unsafe fn get_unchecked<T: ValueMember>(&self) -> &T {
+        T::get_unchecked(self)
+    }
 }
 
-impl ValueMember for f32 {
-    fn get(value: &Value) -> Option<&Self> {
-        if Self::is_holding(value) {
-            unsafe {
-                let mut ptr = std::ptr::null_mut();
-                ffi::vt_Value_Get_float(value.ptr, &mut ptr);
-                Some(&*ptr)
-            }
-        } else {
-            None
+/// The contents of a [`Value`], resolved to a Rust type.
+#[non_exhaustive]
+pub enum ValueKind<'a> {
+    Empty,
+    Bool(&'a bool),
+    Int(&'a i32),
+    Int64(&'a i64),
+    UInt(&'a u32),
+    UChar(&'a u8),
+    Half(&'a f16),
+    Float(&'a f32),
+    Double(&'a f64),
+    Vec2(&'a Vec2),
+    Vec3(&'a Vec3),
+    Vec4(&'a Vec4),
+    Vec2d(&'a DVec2),
+    Vec3d(&'a DVec3),
+    Vec4d(&'a DVec4),
+    Vec2h(&'a gf::Vec2h),
+    Vec3h(&'a gf::Vec3h),
+    Vec4h(&'a gf::Vec4h),
+    Vec2i(&'a IVec2),
+    Vec3i(&'a IVec3),
+    Vec4i(&'a IVec4),
+    Quatf(&'a Quat),
+    Quatd(&'a DQuat),
+    Quath(&'a gf::Quath),
+    Matrix2d(&'a DMat2),
+    Matrix3d(&'a DMat3),
+    Matrix4d(&'a DMat4),
+    TimeCode(&'a sdf::TimeCode),
+    String(cpp::StringRef<'a>),
+    Token(tf::TokenRef<'a>),
+    AssetPath(sdf::AssetPathRef<'a>),
+    Dictionary(DictionaryRef),
+    BoolArray(BoolArrayRef),
+    IntArray(IntArrayRef),
+    Int64Array(Int64ArrayRef),
+    UIntArray(UIntArrayRef),
+    UCharArray(UCharArrayRef),
+    HalfArray(HalfArrayRef),
+    FloatArray(FloatArrayRef),
+    DoubleArray(DoubleArrayRef),
+    Vec2Array(Vec2ArrayRef),
+    Vec3Array(Vec3ArrayRef),
+    Vec4Array(Vec4ArrayRef),
+    Vec2dArray(Vec2dArrayRef),
+    Vec3dArray(Vec3dArrayRef),
+    Vec4dArray(Vec4dArrayRef),
+    Vec2hArray(Vec2hArrayRef),
+    Vec3hArray(Vec3hArrayRef),
+    Vec4hArray(Vec4hArrayRef),
+    Vec2iArray(Vec2iArrayRef),
+    Vec3iArray(Vec3iArrayRef),
+    Vec4iArray(Vec4iArrayRef),
+    QuatfArray(QuatfArrayRef),
+    QuatdArray(QuatdArrayRef),
+    QuathArray(QuathArrayRef),
+    Matrix2dArray(Matrix2dArrayRef),
+    Matrix3dArray(Matrix3dArrayRef),
+    Matrix4dArray(Matrix4dArrayRef),
+    TimeCodeArray(TimeCodeArrayRef),
+    TokenArray(TokenArrayRef),
+    StringArray(StringArrayRef),
+    AssetPathArray(AssetPathArrayRef),
+    /// A type that has no Rust representation yet.
+    Other,
+}
+
+/// A value borrowed from the container that owns it, e.g. an entry of a
+/// `Dictionary`.
+#[repr(transparent)]
+pub struct ValueRef<'a> {
+    pub(crate) ptr: *mut ffi::vt_Value_t,
+    _marker: PhantomData<&'a Value>,
+}
+
+impl<'a> ValueRef<'a> {
+    pub(crate) fn _from_ptr(ptr: *mut ffi::vt_Value_t) -> Self {
+        ValueRef {
+            ptr,
+            _marker: PhantomData,
         }
     }
+}
 
-    fn is_holding(value: &Value) -> bool {
-        unsafe {
-            let mut result = false;
-            ffi::vt_Value_IsHolding_float(value.ptr, &mut result);
-            result
-        }
+impl<'a> std::ops::Deref for ValueRef<'a> {
+    type Target = Value;
+
+    fn deref(&self) -> &Self::Target {
+        unsafe { &*(self as *const ValueRef as *const Value) }
     }
+}
 
-    fn from(member: &Self) -> Value {
+impl Default for Value {
+    fn default() -> Self {
         unsafe {
             let mut ptr = std::ptr::null_mut();
-            ffi::vt_Value_from_float(*member, &mut ptr);
+            ffi::vt_Value_new(&mut ptr);
             Value { ptr }
         }
     }
 }
 
-impl ValueMember for f64 {
-    fn get(value: &Value) -> Option<&Self> {
-        if Self::is_holding(value) {
-            unsafe {
-                let mut ptr = std::ptr::null_mut();
-                ffi::vt_Value_Get_double(value.ptr, &mut ptr);
-                Some(&*ptr)
-            }
-        } else {
-            None
+impl Clone for Value {
+    fn clone(&self) -> Self {
+        unsafe {
+            let mut ptr = std::ptr::null_mut();
+            ffi::vt_Value_copy(self.ptr, &mut ptr);
+            Value { ptr }
         }
     }
+}
 
-    fn is_holding(value: &Value) -> bool {
+impl PartialEq for Value {
+    fn eq(&self, other: &Self) -> bool {
         unsafe {
             let mut result = false;
-            ffi::vt_Value_IsHolding_double(value.ptr, &mut result);
+            ffi::vt_Value_op_eq(self.ptr, other.ptr, &mut result);
             result
         }
     }
+}
+
+impl Hash for Value {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        unsafe {
+            let mut hash = 0;
+            ffi::vt_Value_GetHash(self.ptr, &mut hash);
+            state.write_usize(hash);
+        }
+    }
+}
 
-    fn from(member: &Self) -> Value {
+impl fmt::Display for Value {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         unsafe {
             let mut ptr = std::ptr::null_mut();
-            ffi::vt_Value_from_double(*member, &mut ptr);
-            Value { ptr }
+            ffi::vt_Value_to_string(self.ptr, &mut ptr);
+            let text = cpp::String { ptr };
+            write!(f, "{}", text.as_str())
         }
     }
 }
 
-impl ValueMember for bool {
-    fn get(value: &Value) -> Option<&Self> {
-        if Self::is_holding(value) {
-            unsafe {
-                let mut ptr = std::ptr::null_mut();
-                ffi::vt_Value_Get_bool(value.ptr, &mut ptr);
-                Some(&*ptr)
-            }
+impl fmt::Debug for Value {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.is_empty() {
+            write!(f, "Value(<empty>)")
         } else {
-            None
+            write!(f, "Value({}: {})", self.type_name(), self)
         }
     }
+}
 
-    fn is_holding(value: &Value) -> bool {
-        unsafe {
-            let mut result = false;
-            ffi::vt_Value_IsHolding_bool(value.ptr, &mut result);
-            result
-        }
+impl<'a> fmt::Display for ValueRef<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&**self, f)
     }
+}
 
-    fn from(member: &Self) -> Value {
+impl<'a> fmt::Debug for ValueRef<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(&**self, f)
+    }
+}
+
+impl Drop for Value {
+    fn drop(&mut self) {
         unsafe {
-            let mut ptr = std::ptr::null_mut();
-            ffi::vt_Value_from_bool(*member, &mut ptr);
-            Value { ptr }
+            ffi::vt_Value_dtor(self.ptr);
         }
     }
 }
 
-impl ValueMember for Vec2 {
-    fn get(value: &Value) -> Option<&Self> {
-        if Self::is_holding(value) {
-            unsafe {
-                let mut ptr = std::ptr::null_mut();
-                ffi::vt_Value_Get_GfVec2f(value.ptr, &mut ptr);
-                Some(&*(ptr as *mut Vec2))
-            }
-        } else {
-            None
-        }
+pub trait ValueMember {
+    fn get(value: &Value) -> Option<&Self>;
+    #[doc(hidden)]
+    unsafe fn get_unchecked(value: &Value) -> &Self;
+    fn is_holding(value: &Value) -> bool;
+    fn from(member: &Self) -> Value;
+}
+
+pub trait ValueCast: Sized {
+    /// The Sdf value type name, e.g. `float3` or `int[]`.
+    const TYPE_NAME: &'static str;
+
+    fn can_cast(value: &Value) -> bool;
+    fn cast(value: &Value) -> Option<Self>;
+}
+
+pub trait ToValue {
+    fn to_value(&self) -> Value;
+}
+
+impl<T: ValueMember> ToValue for T {
+    fn to_value(&self) -> Value {
+        T::from(self)
     }
+}
 
-    fn is_holding(value: &Value) -> bool {
-        unsafe {
-            let mut result = false;
-            ffi::vt_Value_IsHolding_GfVec2f(value.ptr, &mut result);
-            result
-        }
+impl ToValue for Value {
+    fn to_value(&self) -> Value {
+        self.clone()
     }
+}
 
-    fn from(member: &Self) -> Value {
+impl ToValue for str {
+    fn to_value(&self) -> Value {
+        let value = CString::new(self).unwrap();
         unsafe {
             let mut ptr = std::ptr::null_mut();
-            ffi::vt_Value_from_GfVec2f(
-                *(member as *const Vec2 as *const ffi::gf_Vec2f_t),
-                &mut ptr,
-            );
+            ffi::vt_Value_from_string(value.as_ptr() as *mut i8, &mut ptr);
             Value { ptr }
         }
     }
 }
 
-impl ValueMember for Vec3 {
-    fn get(value: &Value) -> Option<&Self> {
-        if Self::is_holding(value) {
-            unsafe {
-                let mut ptr = std::ptr::null_mut();
-                ffi::vt_Value_Get_GfVec3f(value.ptr, &mut ptr);
-                Some(&*(ptr as *mut Vec3))
-            }
-        } else {
-            None
+impl ToValue for String {
+    fn to_value(&self) -> Value {
+        self.as_str().to_value()
+    }
+}
+
+impl ToValue for tf::Token {
+    fn to_value(&self) -> Value {
+        unsafe {
+            let mut ptr = std::ptr::null_mut();
+            ffi::vt_Value_from_TfToken(self.ptr, &mut ptr);
+            Value { ptr }
         }
     }
+}
 
-    fn is_holding(value: &Value) -> bool {
+impl ToValue for sdf::AssetPath {
+    fn to_value(&self) -> Value {
         unsafe {
-            let mut result = false;
-            ffi::vt_Value_IsHolding_GfVec3f(value.ptr, &mut result);
-            result
+            let mut ptr = std::ptr::null_mut();
+            ffi::vt_Value_from_SdfAssetPath(self.ptr, &mut ptr);
+            Value { ptr }
         }
     }
+}
 
-    fn from(member: &Self) -> Value {
+impl ToValue for TokenArray {
+    fn to_value(&self) -> Value {
         unsafe {
             let mut ptr = std::ptr::null_mut();
-            ffi::vt_Value_from_GfVec3f(
-                *(member as *const Vec3 as *const ffi::gf_Vec3f_t),
-                &mut ptr,
-            );
+            ffi::vt_Value_from_VtTokenArray(self.ptr, &mut ptr);
             Value { ptr }
         }
     }
 }
 
-impl ValueMember for Vec4 {
-    fn get(value: &Value) -> Option<&Self> {
-        if Self::is_holding(value) {
-            unsafe {
+macro_rules! value_member {
+    (
+        $ty:ty,
+        $ffi_ty:ty,
+        $is_holding:ident,
+        $get:ident,
+        $from:ident,
+        $can_cast:ident,
+        $cast:ident,
+        $type_name:literal
+    ) => {
+        impl ValueMember for $ty {
+            fn get(value: &Value) -> Option<&Self> {
+                if Self::is_holding(value) {
+                    unsafe { Some(Self::get_unchecked(value)) }
+                } else {
+                    None
+                }
+            }
+
+            unsafe fn get_unchecked(value: &Value) -> &Self {
                 let mut ptr = std::ptr::null_mut();
-                ffi::vt_Value_Get_GfVec4f(value.ptr, &mut ptr);
-                Some(&*(ptr as *mut Vec4))
+                ffi::$get(value.ptr, &mut ptr);
+                &*(ptr as *const $ty)
             }
-        } else {
-            None
-        }
-    }
 
-    fn is_holding(value: &Value) -> bool {
-        unsafe {
-            let mut result = false;
-            ffi::vt_Value_IsHolding_GfVec4f(value.ptr, &mut result);
-            result
+            fn is_holding(value: &Value) -> bool {
+                unsafe {
+                    let mut result = false;
+                    ffi::$is_holding(value.ptr, &mut result);
+                    result
+                }
+            }
+
+            fn from(member: &Self) -> Value {
+                unsafe {
+                    let mut ptr = std::ptr::null_mut();
+                    ffi::$from(*(member as *const $ty as *const $ffi_ty), &mut ptr);
+                    Value { ptr }
+                }
+            }
         }
-    }
 
-    fn from(member: &Self) -> Value {
-        unsafe {
-            let mut ptr = std::ptr::null_mut();
-            ffi::vt_Value_from_GfVec4f(
-                *(member as *const Vec4 as *const ffi::gf_Vec4f_t),
-                &mut ptr,
-            );
-            Value { ptr }
+        impl ValueCast for $ty {
+            const TYPE_NAME: &'static str = $type_name;
+
+            fn can_cast(value: &Value) -> bool {
+                unsafe {
+                    let mut result = false;
+                    ffi::$can_cast(value.ptr, &mut result);
+                    result
+                }
+            }
+
+            fn cast(value: &Value) -> Option<Self> {
+                let value = unsafe {
+                    let mut ptr = std::ptr::null_mut();
+                    ffi::$cast(value.ptr, &mut ptr);
+                    Value { ptr }
+                };
+                <$ty as ValueMember>::get(&value).copied()
+            }
         }
-    }
+    };
 }
+
+value_member!(
+    i32,
+    i32,
+    vt_Value_IsHolding_int,
+    vt_Value_Get_int,
+    vt_Value_from_int,
+    vt_Value_CanCast_int,
+    vt_Value_Cast_int,
+    "int"
+);
+
+value_member!(
+    i64,
+    i64,
+    vt_Value_IsHolding_int64,
+    vt_Value_Get_int64,
+    vt_Value_from_int64,
+    vt_Value_CanCast_int64,
+    vt_Value_Cast_int64,
+    "int64"
+);
+
+value_member!(
+    u32,
+    u32,
+    vt_Value_IsHolding_uint,
+    vt_Value_Get_uint,
+    vt_Value_from_uint,
+    vt_Value_CanCast_uint,
+    vt_Value_Cast_uint,
+    "uint"
+);
+
+value_member!(
+    u8,
+    u8,
+    vt_Value_IsHolding_uchar,
+    vt_Value_Get_uchar,
+    vt_Value_from_uchar,
+    vt_Value_CanCast_uchar,
+    vt_Value_Cast_uchar,
+    "uchar"
+);
+
+value_member!(
+    f16,
+    ffi::gf_Half_t,
+    vt_Value_IsHolding_GfHalf,
+    vt_Value_Get_GfHalf,
+    vt_Value_from_GfHalf,
+    vt_Value_CanCast_GfHalf,
+    vt_Value_Cast_GfHalf,
+    "half"
+);
+
+value_member!(
+    f32,
+    f32,
+    vt_Value_IsHolding_float,
+    vt_Value_Get_float,
+    vt_Value_from_float,
+    vt_Value_CanCast_float,
+    vt_Value_Cast_float,
+    "float"
+);
+
+value_member!(
+    f64,
+    f64,
+    vt_Value_IsHolding_double,
+    vt_Value_Get_double,
+    vt_Value_from_double,
+    vt_Value_CanCast_double,
+    vt_Value_Cast_double,
+    "double"
+);
+
+value_member!(
+    bool,
+    bool,
+    vt_Value_IsHolding_bool,
+    vt_Value_Get_bool,
+    vt_Value_from_bool,
+    vt_Value_CanCast_bool,
+    vt_Value_Cast_bool,
+    "bool"
+);
+
+value_member!(
+    Vec2,
+    ffi::gf_Vec2f_t,
+    vt_Value_IsHolding_GfVec2f,
+    vt_Value_Get_GfVec2f,
+    vt_Value_from_GfVec2f,
+    vt_Value_CanCast_GfVec2f,
+    vt_Value_Cast_GfVec2f,
+    "float2"
+);
+
+value_member!(
+    Vec3,
+    ffi::gf_Vec3f_t,
+    vt_Value_IsHolding_GfVec3f,
+    vt_Value_Get_GfVec3f,
+    vt_Value_from_GfVec3f,
+    vt_Value_CanCast_GfVec3f,
+    vt_Value_Cast_GfVec3f,
+    "float3"
+);
+
+value_member!(
+    Vec4,
+    ffi::gf_Vec4f_t,
+    vt_Value_IsHolding_GfVec4f,
+    vt_Value_Get_GfVec4f,
+    vt_Value_from_GfVec4f,
+    vt_Value_CanCast_GfVec4f,
+    vt_Value_Cast_GfVec4f,
+    "float4"
+);
+
+value_member!(
+    DVec2,
+    ffi::gf_Vec2d_t,
+    vt_Value_IsHolding_GfVec2d,
+    vt_Value_Get_GfVec2d,
+    vt_Value_from_GfVec2d,
+    vt_Value_CanCast_GfVec2d,
+    vt_Value_Cast_GfVec2d,
+    "double2"
+);
+
+value_member!(
+    DVec3,
+    ffi::gf_Vec3d_t,
+    vt_Value_IsHolding_GfVec3d,
+    vt_Value_Get_GfVec3d,
+    vt_Value_from_GfVec3d,
+    vt_Value_CanCast_GfVec3d,
+    vt_Value_Cast_GfVec3d,
+    "double3"
+);
+
+value_member!(
+    DVec4,
+    ffi::gf_Vec4d_t,
+    vt_Value_IsHolding_GfVec4d,
+    vt_Value_Get_GfVec4d,
+    vt_Value_from_GfVec4d,
+    vt_Value_CanCast_GfVec4d,
+    vt_Value_Cast_GfVec4d,
+    "double4"
+);
+
+value_member!(
+    gf::Vec2h,
+    ffi::gf_Vec2h_t,
+    vt_Value_IsHolding_GfVec2h,
+    vt_Value_Get_GfVec2h,
+    vt_Value_from_GfVec2h,
+    vt_Value_CanCast_GfVec2h,
+    vt_Value_Cast_GfVec2h,
+    "half2"
+);
+
+value_member!(
+    gf::Vec3h,
+    ffi::gf_Vec3h_t,
+    vt_Value_IsHolding_GfVec3h,
+    vt_Value_Get_GfVec3h,
+    vt_Value_from_GfVec3h,
+    vt_Value_CanCast_GfVec3h,
+    vt_Value_Cast_GfVec3h,
+    "half3"
+);
+
+value_member!(
+    gf::Vec4h,
+    ffi::gf_Vec4h_t,
+    vt_Value_IsHolding_GfVec4h,
+    vt_Value_Get_GfVec4h,
+    vt_Value_from_GfVec4h,
+    vt_Value_CanCast_GfVec4h,
+    vt_Value_Cast_GfVec4h,
+    "half4"
+);
+
+value_member!(
+    IVec2,
+    ffi::gf_Vec2i_t,
+    vt_Value_IsHolding_GfVec2i,
+    vt_Value_Get_GfVec2i,
+    vt_Value_from_GfVec2i,
+    vt_Value_CanCast_GfVec2i,
+    vt_Value_Cast_GfVec2i,
+    "int2"
+);
+
+value_member!(
+    IVec3,
+    ffi::gf_Vec3i_t,
+    vt_Value_IsHolding_GfVec3i,
+    vt_Value_Get_GfVec3i,
+    vt_Value_from_GfVec3i,
+    vt_Value_CanCast_GfVec3i,
+    vt_Value_Cast_GfVec3i,
+    "int3"
+);
+
+value_member!(
+    IVec4,
+    ffi::gf_Vec4i_t,
+    vt_Value_IsHolding_GfVec4i,
+    vt_Value_Get_GfVec4i,
+    vt_Value_from_GfVec4i,
+    vt_Value_CanCast_GfVec4i,
+    vt_Value_Cast_GfVec4i,
+    "int4"
+);
+
+value_member!(
+    Quat,
+    ffi::gf_Quatf_t,
+    vt_Value_IsHolding_GfQuatf,
+    vt_Value_Get_GfQuatf,
+    vt_Value_from_GfQuatf,
+    vt_Value_CanCast_GfQuatf,
+    vt_Value_Cast_GfQuatf,
+    "quatf"
+);
+
+value_member!(
+    DQuat,
+    ffi::gf_Quatd_t,
+    vt_Value_IsHolding_GfQuatd,
+    vt_Value_Get_GfQuatd,
+    vt_Value_from_GfQuatd,
+    vt_Value_CanCast_GfQuatd,
+    vt_Value_Cast_GfQuatd,
+    "quatd"
+);
+
+value_member!(
+    gf::Quath,
+    ffi::gf_Quath_t,
+    vt_Value_IsHolding_GfQuath,
+    vt_Value_Get_GfQuath,
+    vt_Value_from_GfQuath,
+    vt_Value_CanCast_GfQuath,
+    vt_Value_Cast_GfQuath,
+    "quath"
+);
+
+value_member!(
+    DMat2,
+    ffi::gf_Matrix2d_t,
+    vt_Value_IsHolding_GfMatrix2d,
+    vt_Value_Get_GfMatrix2d,
+    vt_Value_from_GfMatrix2d,
+    vt_Value_CanCast_GfMatrix2d,
+    vt_Value_Cast_GfMatrix2d,
+    "matrix2d"
+);
+
+value_member!(
+    DMat3,
+    ffi::gf_Matrix3d_t,
+    vt_Value_IsHolding_GfMatrix3d,
+    vt_Value_Get_GfMatrix3d,
+    vt_Value_from_GfMatrix3d,
+    vt_Value_CanCast_GfMatrix3d,
+    vt_Value_Cast_GfMatrix3d,
+    "matrix3d"
+);
+
+value_member!(
+    DMat4,
+    ffi::gf_Matrix4d_t,
+    vt_Value_IsHolding_GfMatrix4d,
+    vt_Value_Get_GfMatrix4d,
+    vt_Value_from_GfMatrix4d,
+    vt_Value_CanCast_GfMatrix4d,
+    vt_Value_Cast_GfMatrix4d,
+    "matrix4d"
+);
+
+value_member!(
+    sdf::TimeCode,
+    ffi::sdf_TimeCode_t,
+    vt_Value_IsHolding_SdfTimeCode,
+    vt_Value_Get_SdfTimeCode,
+    vt_Value_from_SdfTimeCode,
+    vt_Value_CanCast_SdfTimeCode,
+    vt_Value_Cast_SdfTimeCode,
+    "timecode"
+);